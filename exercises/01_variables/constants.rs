@@ -0,0 +1,38 @@
+// Besides `let` bindings, Rust has two kinds of global values: constants
+// declared with `const` and statics declared with `static`.
+//
+// Hint: A `const` is inlined wherever it is used, while a `static` lives at a
+// single location in memory for the whole run of the program. Both always need
+// an explicit type annotation because the compiler doesn't infer the types of
+// global items.
+
+// TODO: Complete the declarations below.
+const MAX_SCORE: u32 = 100;
+static GREETING: &str = "Hello";
+
+fn score_message(name: &str, score: u32) -> String {
+    format!("{GREETING}, {name}! You scored {score} out of {MAX_SCORE}.")
+}
+
+fn main() {
+    println!("{}", score_message("Ferris", 42));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message() {
+        assert_eq!(
+            score_message("Ferris", 42),
+            "Hello, Ferris! You scored 42 out of 100.",
+        );
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(MAX_SCORE, 100);
+        assert_eq!(GREETING, "Hello");
+    }
+}
@@ -0,0 +1,37 @@
+// Shadowing isn't limited to values of the same type. A common use is to
+// parse a string and keep the same name for the parsed value.
+//
+// Hint: After `let s: i32 = ...;`, the name `s` refers to the new `i32`
+// binding and the original `&str` is no longer reachable by that name. This
+// saves you from inventing names like `s_str` and `s_num`.
+
+// TODO: Parse `s` and shadow it with the parsed `i32`. If parsing fails,
+// return an error message like "`abc` isn't a number".
+fn to_number(s: &str) -> Result<i32, String> {
+    let s: i32 = match s.trim().parse() {
+        Ok(n) => n,
+        Err(_) => return Err(format!("`{s}` isn't a number")),
+    };
+
+    Ok(s)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_number() {
+        assert_eq!(to_number("42"), Ok(42));
+        assert_eq!(to_number(" -7 "), Ok(-7));
+    }
+
+    #[test]
+    fn invalid_number() {
+        assert_eq!(to_number("abc"), Err(String::from("`abc` isn't a number")));
+    }
+}
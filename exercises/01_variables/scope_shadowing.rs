@@ -0,0 +1,35 @@
+// A variable that shadows another one inside a block only lives until the end
+// of that block. After the block, the name refers to the outer variable again.
+//
+// Hint: Curly braces `{ }` open a new scope. A `let` inside it can reuse the
+// name of an outer variable without changing that outer variable. The block
+// can also evaluate to a value through its last expression.
+
+fn compute() -> i32 {
+    let x = 5;
+
+    let inner = {
+        // TODO: Shadow `x` in this block with a new `x` that is twice as large
+        // as the outer `x`.
+        let x = x * 2;
+        x + 1
+    };
+
+    // Here, `x` is the outer `x` again.
+    x + inner
+}
+
+fn main() {
+    println!("{}", compute());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shadow_ends_with_block() {
+        // 5 (outer `x`) + 11 (inner `x` + 1)
+        assert_eq!(compute(), 16);
+    }
+}
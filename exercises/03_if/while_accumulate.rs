@@ -0,0 +1,44 @@
+// A `while` loop repeats its body as long as its condition is `true`. It is
+// often used together with a mutable variable that moves towards ending the
+// loop.
+//
+// Hint: `n % 10` is the last digit of `n` and `n /= 10` removes that digit.
+// Repeat both until `n` becomes 0.
+
+// TODO: Return the sum of all decimal digits of `n` using a `while` loop.
+fn digit_sum(mut n: u32) -> u32 {
+    let mut sum = 0;
+
+    while n > 0 {
+        sum += n % 10;
+        n /= 10;
+    }
+
+    sum
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_digit() {
+        assert_eq!(digit_sum(7), 7);
+    }
+
+    #[test]
+    fn multiple_digits() {
+        assert_eq!(digit_sum(1234), 10);
+        assert_eq!(digit_sum(909), 18);
+        assert_eq!(digit_sum(u32::MAX), 57);
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(digit_sum(0), 0);
+    }
+}
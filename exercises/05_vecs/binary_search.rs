@@ -0,0 +1,50 @@
+// Searching a sorted slice doesn't require looking at every element. The
+// `binary_search` method halves the searched range in every step.
+//
+// Hint: `binary_search` returns `Ok(index)` if the value was found. Otherwise,
+// it returns `Err(index)` where `index` is the position at which the value
+// could be inserted while keeping the slice sorted.
+
+// TODO: Search for `target` in the sorted slice `v`.
+fn find_sorted(v: &[i32], target: i32) -> Result<usize, usize> {
+    v.binary_search(&target)
+}
+
+// TODO: Insert `value` into the sorted vector `v` so that it stays sorted.
+fn insert_sorted(v: &mut Vec<i32>, value: i32) {
+    let index = match v.binary_search(&value) {
+        Ok(index) | Err(index) => index,
+    };
+    v.insert(index, value);
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_present() {
+        assert_eq!(find_sorted(&[1, 3, 5, 7, 9], 7), Ok(3));
+    }
+
+    #[test]
+    fn find_absent() {
+        assert_eq!(find_sorted(&[1, 3, 5, 7, 9], 4), Err(2));
+        assert_eq!(find_sorted(&[1, 3, 5, 7, 9], 10), Err(5));
+        assert_eq!(find_sorted(&[], 1), Err(0));
+    }
+
+    #[test]
+    fn insert_keeps_sorted() {
+        let mut v = vec![1, 3, 5];
+        insert_sorted(&mut v, 4);
+        insert_sorted(&mut v, 0);
+        insert_sorted(&mut v, 6);
+        insert_sorted(&mut v, 3);
+        assert_eq!(v, [0, 1, 3, 3, 4, 5, 6]);
+    }
+}
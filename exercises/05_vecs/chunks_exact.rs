@@ -0,0 +1,45 @@
+// A slice can be split into chunks of a fixed size. If its length isn't a
+// multiple of the chunk size, the last elements don't form a full chunk and
+// need to be handled separately.
+//
+// Hint: `chunks_exact(size)` only yields full chunks and skips the tail. The
+// skipped elements are returned by the iterator's `remainder()` method. The
+// size must not be 0.
+
+// TODO: Return the sum of every full chunk of `size` elements, and the
+// elements that don't fit into a full chunk.
+fn sum_chunks(v: &[i32], size: usize) -> (Vec<i32>, Vec<i32>) {
+    let chunks = v.chunks_exact(size);
+    let remainder = chunks.remainder().to_vec();
+    let sums = chunks.map(|chunk| chunk.iter().sum()).collect();
+
+    (sums, remainder)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_multiple() {
+        assert_eq!(sum_chunks(&[1, 2, 3, 4, 5, 6], 2), (vec![3, 7, 11], vec![]));
+    }
+
+    #[test]
+    fn with_remainder() {
+        assert_eq!(
+            sum_chunks(&[1, 2, 3, 4, 5, 6, 7], 3),
+            (vec![6, 15], vec![7])
+        );
+        assert_eq!(sum_chunks(&[1, 2], 3), (vec![], vec![1, 2]));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sum_chunks(&[], 4), (vec![], vec![]));
+    }
+}
@@ -0,0 +1,45 @@
+// Collections can be converted into each other by iterating over one and
+// collecting into another. The target collection decides what happens to the
+// elements, e.g. a set drops duplicates.
+//
+// Hint: A `BTreeSet` keeps its elements sorted and stores each one only once.
+// Collecting into it and then into a `Vec` both deduplicates and sorts.
+
+use std::collections::{BTreeSet, HashSet};
+
+// TODO: Convert the set into a `Vec` sorted in ascending order.
+fn to_sorted_vec(set: HashSet<i32>) -> Vec<i32> {
+    let mut v: Vec<i32> = set.into_iter().collect();
+    v.sort();
+    v
+}
+
+// TODO: Remove all duplicates from `v` and sort it using a `BTreeSet`.
+fn dedup_sorted(v: Vec<i32>) -> Vec<i32> {
+    v.into_iter()
+        .collect::<BTreeSet<i32>>()
+        .into_iter()
+        .collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_from_set() {
+        let set = HashSet::from([42, -3, 7, 0, 19]);
+        assert_eq!(to_sorted_vec(set), [-3, 0, 7, 19, 42]);
+    }
+
+    #[test]
+    fn dedup_and_sort() {
+        assert_eq!(dedup_sorted(vec![3, 1, 3, 2, 1, 5]), [1, 2, 3, 5]);
+        assert_eq!(dedup_sorted(vec![4, 4, 4]), [4]);
+        assert!(dedup_sorted(Vec::new()).is_empty());
+    }
+}
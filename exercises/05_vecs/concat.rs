@@ -0,0 +1,48 @@
+// A slice of vectors can be turned into a single flat vector without writing
+// any loops.
+//
+// Hint: `[T]::concat` glues all inner vectors together. `[T]::join(&sep)`
+// does the same, but puts the separator between every two neighbouring groups
+// (and not before the first or after the last one).
+
+// TODO: Flatten the nested vectors into a single vector using `concat`.
+fn flatten(nested: &[Vec<i32>]) -> Vec<i32> {
+    nested.concat()
+}
+
+// TODO: Flatten the nested vectors using `join` with `sep` between groups.
+fn join_with(nested: &[Vec<i32>], sep: i32) -> Vec<i32> {
+    nested.join(&sep)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_nested() {
+        assert_eq!(
+            flatten(&[vec![1, 2], vec![], vec![3], vec![4, 5]]),
+            [1, 2, 3, 4, 5],
+        );
+    }
+
+    #[test]
+    fn join_nested() {
+        assert_eq!(
+            join_with(&[vec![1, 2], vec![3], vec![4, 5]], 0),
+            [1, 2, 0, 3, 0, 4, 5],
+        );
+        assert_eq!(join_with(&[vec![1, 2]], 0), [1, 2]);
+    }
+
+    #[test]
+    fn empty_outer() {
+        assert!(flatten(&[]).is_empty());
+        assert!(join_with(&[], 0).is_empty());
+    }
+}
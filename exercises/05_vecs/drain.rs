@@ -0,0 +1,42 @@
+// `Vec::drain` removes a range of elements from a vector and hands them to you
+// at the same time, so you don't have to copy them out before removing them.
+//
+// Hint: `v.drain(start..end)` returns an iterator over the removed elements.
+// The remaining elements are shifted to close the gap, even if you don't
+// consume the iterator completely.
+
+// TODO: Remove the elements from index `start` (included) to index `end`
+// (excluded) from `v` and return them.
+fn take_range(v: &mut Vec<i32>, start: usize, end: usize) -> Vec<i32> {
+    v.drain(start..end).collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn middle_range() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        assert_eq!(take_range(&mut v, 1, 3), [2, 3]);
+        assert_eq!(v, [1, 4, 5]);
+    }
+
+    #[test]
+    fn whole_vec() {
+        let mut v = vec![1, 2, 3];
+        assert_eq!(take_range(&mut v, 0, 3), [1, 2, 3]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn empty_range() {
+        let mut v = vec![1, 2, 3];
+        assert!(take_range(&mut v, 2, 2).is_empty());
+        assert_eq!(v, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,36 @@
+// To build a new vector from some of the elements of a slice, you don't need
+// to create an empty vector and push into it in a loop. An iterator chain can
+// select, transform and collect the elements in one go.
+//
+// Hint: Put `filter` before `map` in the chain. This way, the elements that
+// are dropped anyway don't get transformed first.
+
+// TODO: Return the positive elements of `v` doubled, in their original order.
+fn positives_doubled(v: &[i32]) -> Vec<i32> {
+    v.iter().filter(|&&n| n > 0).map(|&n| n * 2).collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_and_double_positives() {
+        assert_eq!(positives_doubled(&[1, 5, 3]), [2, 10, 6]);
+    }
+
+    #[test]
+    fn drop_negatives_and_zero() {
+        assert_eq!(positives_doubled(&[-4, 0, 2, -1, 7]), [4, 14]);
+        assert!(positives_doubled(&[-1, 0, -3]).is_empty());
+    }
+
+    #[test]
+    fn empty() {
+        assert!(positives_doubled(&[]).is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+// Taking the first or last few elements of a slice usually gives you another
+// slice. If the number of elements is fixed, you can get an array reference
+// instead, which carries the length in its type.
+//
+// Hint: `v.first_chunk::<2>()` returns `Some(&[T; 2])` with the first two
+// elements, or `None` if the slice is shorter than that. `last_chunk` does the
+// same for the end of the slice.
+
+// TODO: Return the first two elements of `v` as an array reference.
+fn first_two(v: &[i32]) -> Option<&[i32; 2]> {
+    v.first_chunk::<2>()
+}
+
+// TODO: Return the last two elements of `v` as an array reference.
+fn last_two(v: &[i32]) -> Option<&[i32; 2]> {
+    v.last_chunk::<2>()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_short() {
+        assert_eq!(first_two(&[]), None);
+        assert_eq!(last_two(&[]), None);
+        assert_eq!(first_two(&[1]), None);
+        assert_eq!(last_two(&[1]), None);
+    }
+
+    #[test]
+    fn exact_length() {
+        assert_eq!(first_two(&[1, 2]), Some(&[1, 2]));
+        assert_eq!(last_two(&[1, 2]), Some(&[1, 2]));
+    }
+
+    #[test]
+    fn longer() {
+        let v = [1, 2, 3, 4, 5];
+        assert_eq!(first_two(&v), Some(&[1, 2]));
+        assert_eq!(last_two(&v), Some(&[4, 5]));
+    }
+}
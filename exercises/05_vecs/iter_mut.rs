@@ -0,0 +1,50 @@
+// To change the elements of a slice in place, iterate over mutable references
+// to them with `iter_mut`.
+//
+// Hint: `iter_mut()` yields `&mut i32` items. To read or overwrite the value
+// behind such a reference, dereference it with `*`, e.g. `*element *= 2`.
+
+// TODO: Double every element of `v` in place.
+fn double_all(v: &mut [i32]) {
+    for element in v.iter_mut() {
+        *element *= 2;
+    }
+}
+
+// TODO: Replace every negative element of `v` with 0.
+fn zero_negatives(v: &mut [i32]) {
+    for element in v.iter_mut().filter(|element| **element < 0) {
+        *element = 0;
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doubling() {
+        let mut v = [1, -2, 0, 5];
+        double_all(&mut v);
+        assert_eq!(v, [2, -4, 0, 10]);
+    }
+
+    #[test]
+    fn clamping() {
+        let mut v = [3, -1, 0, -7, 2];
+        zero_negatives(&mut v);
+        assert_eq!(v, [3, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut v: [i32; 0] = [];
+        double_all(&mut v);
+        zero_negatives(&mut v);
+        assert!(v.is_empty());
+    }
+}
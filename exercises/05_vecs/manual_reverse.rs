@@ -0,0 +1,58 @@
+// Slices already have a `reverse` method, but implementing it yourself is a
+// good exercise in working with indices and mutable slices.
+//
+// Hint: Use two indices, one starting at the front and one at the back.
+// `v.swap(i, j)` exchanges the elements at both positions. Move the indices
+// towards each other until they meet.
+
+// TODO: Reverse `v` in place without calling `reverse()`.
+fn reverse(v: &mut [i32]) {
+    if v.is_empty() {
+        return;
+    }
+
+    let mut front = 0;
+    let mut back = v.len() - 1;
+    while front < back {
+        v.swap(front, back);
+        front += 1;
+        back -= 1;
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_length() {
+        let mut v = [1, 2, 3, 4];
+        reverse(&mut v);
+        assert_eq!(v, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn odd_length() {
+        let mut v = [1, 2, 3, 4, 5];
+        reverse(&mut v);
+        assert_eq!(v, [5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn one_element() {
+        let mut v = [42];
+        reverse(&mut v);
+        assert_eq!(v, [42]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut v: [i32; 0] = [];
+        reverse(&mut v);
+        assert!(v.is_empty());
+    }
+}
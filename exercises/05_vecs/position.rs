@@ -0,0 +1,48 @@
+// Finding the index of an element in a slice doesn't need a manual loop with a
+// counter. Iterators can search for the first element matching a condition.
+//
+// Hint: `iter().position(...)` scans from left to right and returns the index
+// of the first match. `iter().rposition(...)` scans from right to left, but
+// still returns the index counted from the start.
+
+// TODO: Return the index of the first occurrence of `target` in `v`.
+fn first_index(v: &[i32], target: i32) -> Option<usize> {
+    v.iter().position(|&n| n == target)
+}
+
+// TODO: Return the index of the last occurrence of `target` in `v`.
+fn last_index(v: &[i32], target: i32) -> Option<usize> {
+    v.iter().rposition(|&n| n == target)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_duplicates() {
+        let v = [4, 2, 7, 2, 9, 2, 4];
+        assert_eq!(first_index(&v, 2), Some(1));
+        assert_eq!(last_index(&v, 2), Some(5));
+        assert_eq!(first_index(&v, 4), Some(0));
+        assert_eq!(last_index(&v, 4), Some(6));
+    }
+
+    #[test]
+    fn single_occurrence() {
+        let v = [4, 2, 7, 2, 9];
+        assert_eq!(first_index(&v, 9), Some(4));
+        assert_eq!(last_index(&v, 9), Some(4));
+    }
+
+    #[test]
+    fn absent() {
+        assert_eq!(first_index(&[1, 2, 3], 5), None);
+        assert_eq!(last_index(&[1, 2, 3], 5), None);
+        assert_eq!(first_index(&[], 5), None);
+    }
+}
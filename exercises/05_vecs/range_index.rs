@@ -0,0 +1,47 @@
+// Indexing a slice with a range gives you a sub-slice without copying any
+// elements. The range must stay within the bounds of the slice, otherwise the
+// program panics.
+//
+// Hint: `&v[1..v.len() - 1]` skips the first and the last element. For a
+// slice with fewer than 2 elements, `v.len() - 1` would underflow or the range
+// would be invalid, so handle those slices before slicing.
+
+// TODO: Return all elements of `v` except the first and the last one. For
+// slices with fewer than 3 elements, return an empty slice.
+fn middle(v: &[i32]) -> &[i32] {
+    if v.len() < 2 {
+        return &[];
+    }
+
+    &v[1..v.len() - 1]
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert!(middle(&[]).is_empty());
+    }
+
+    #[test]
+    fn one_element() {
+        assert!(middle(&[1]).is_empty());
+    }
+
+    #[test]
+    fn two_elements() {
+        // Nothing is left between the first and the last element.
+        assert!(middle(&[1, 2]).is_empty());
+    }
+
+    #[test]
+    fn five_elements() {
+        assert_eq!(middle(&[1, 2, 3, 4, 5]), [2, 3, 4]);
+    }
+}
@@ -0,0 +1,80 @@
+// Slices can reorder their elements in place without allocating a new vector,
+// e.g. by rotating or reversing them.
+//
+// Hint: `slice.rotate_left(mid)` panics if `mid` is greater than the length of
+// the slice. Rotating by the length is the same as not rotating at all, so
+// reduce `n` with the remainder operator `%` first (but watch out for an empty
+// slice, dividing by zero panics too).
+
+// TODO: Rotate the elements of `v` to the left by `n` positions. `n` can be
+// larger than the length of `v`.
+fn rotate_left(v: &mut [i32], n: usize) {
+    if v.is_empty() {
+        return;
+    }
+
+    v.rotate_left(n % v.len());
+}
+
+// TODO: Reverse the order of the elements of `v`.
+fn reverse_in_place(v: &mut [i32]) {
+    v.reverse();
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_by_zero() {
+        let mut v = vec![1, 2, 3, 4];
+        rotate_left(&mut v, 0);
+        assert_eq!(v, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rotate_by_some() {
+        let mut v = vec![1, 2, 3, 4];
+        rotate_left(&mut v, 1);
+        assert_eq!(v, [2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn rotate_by_len() {
+        let mut v = vec![1, 2, 3, 4];
+        rotate_left(&mut v, 4);
+        assert_eq!(v, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rotate_by_more_than_len() {
+        let mut v = vec![1, 2, 3, 4];
+        rotate_left(&mut v, 6);
+        assert_eq!(v, [3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn rotate_empty() {
+        let mut v: Vec<i32> = Vec::new();
+        rotate_left(&mut v, 3);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn reverse_odd_len() {
+        let mut v = vec![1, 2, 3];
+        reverse_in_place(&mut v);
+        assert_eq!(v, [3, 2, 1]);
+    }
+
+    #[test]
+    fn reverse_even_len() {
+        let mut v = vec![1, 2, 3, 4];
+        reverse_in_place(&mut v);
+        assert_eq!(v, [4, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,54 @@
+// Deriving `Debug` lets you print a struct with `{:?}`. For nested structs,
+// the output quickly gets hard to read on a single line. The alternate
+// formatter `{:#?}` prints every field on its own line with indentation.
+//
+// Hint: `#[derive(Debug)]` only works if all fields implement `Debug`, so
+// the inner struct needs the derive too. The `#` flag in `{data:#?}` selects
+// the pretty-printed output.
+
+// TODO: Derive `Debug` for both structs.
+#[derive(Debug)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug)]
+struct Data {
+    name: String,
+    position: Position,
+}
+
+// TODO: Return the pretty-printed debug output of `data`.
+fn render(data: &Data) -> String {
+    format!("{data:#?}")
+}
+
+fn main() {
+    let data = Data {
+        name: String::from("Ferris"),
+        position: Position { x: 1, y: -2 },
+    };
+    println!("{}", render(&data));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_output() {
+        let data = Data {
+            name: String::from("Ferris"),
+            position: Position { x: 1, y: -2 },
+        };
+        let output = render(&data);
+
+        assert!(output.contains("name: \"Ferris\""));
+        assert!(output.contains("position: Position {"));
+        // The nested fields are indented twice.
+        assert!(output.contains("\n        x: 1,\n"));
+        assert!(output.contains("\n        y: -2,\n"));
+        assert!(output.lines().count() > 1);
+    }
+}
@@ -0,0 +1,59 @@
+// Methods have access to the instance they are called on through `self`. How
+// they access it depends on the kind of receiver in their signature.
+//
+// Hint: `&self` borrows the instance immutably (reading), `&mut self` borrows
+// it mutably (modifying), and `self` takes ownership of it (consuming).
+
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    // TODO: Increase the count by one.
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    // TODO: Set the count back to zero.
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    // TODO: Return the current count.
+    fn value(&self) -> u32 {
+        self.count
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_at_zero() {
+        assert_eq!(Counter::new().value(), 0);
+    }
+
+    #[test]
+    fn increment_and_reset() {
+        let mut counter = Counter::new();
+        counter.increment();
+        counter.increment();
+        counter.increment();
+        assert_eq!(counter.value(), 3);
+
+        counter.reset();
+        assert_eq!(counter.value(), 0);
+
+        counter.increment();
+        assert_eq!(counter.value(), 1);
+    }
+}
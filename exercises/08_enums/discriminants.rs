@@ -0,0 +1,33 @@
+// Every variant of an enum without data has an integer discriminant. By
+// default, they are numbered from 0, but you can also assign them yourself.
+//
+// Hint: A field-less enum can be cast to an integer type with `as`, e.g.
+// `Status::Ok as u16`. The result is the variant's discriminant.
+
+#[derive(Clone, Copy)]
+enum Status {
+    Ok = 200,
+    NotFound = 404,
+    Error = 500,
+}
+
+// TODO: Return the numeric HTTP status code of `s`.
+fn code(s: Status) -> u16 {
+    s as u16
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes() {
+        assert_eq!(code(Status::Ok), 200);
+        assert_eq!(code(Status::NotFound), 404);
+        assert_eq!(code(Status::Error), 500);
+    }
+}
@@ -0,0 +1,69 @@
+// Enums can be generic just like structs. `Option<T>` and `Result<T, E>` from
+// the standard library are generic enums. Let's build one ourselves that holds
+// either a value of type `L` or a value of type `R`.
+//
+// Hint: Every variant can use a different generic parameter. `map_left` takes
+// `self` by value so that it can move the inner value out, pass it to `f` and
+// put the result into a new `Either`.
+
+#[derive(Debug, PartialEq)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    // TODO: Return `true` if `self` is the `Left` variant.
+    fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    // TODO: Return `true` if `self` is the `Right` variant.
+    fn is_right(&self) -> bool {
+        !self.is_left()
+    }
+
+    // TODO: Apply `f` to the value if `self` is `Left`. Leave `Right`
+    // unchanged.
+    fn map_left<F: Fn(L) -> L>(self, f: F) -> Self {
+        match self {
+            Self::Left(value) => Self::Left(f(value)),
+            Self::Right(value) => Self::Right(value),
+        }
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn left_or_right() {
+        let left: Either<i32, String> = Either::Left(1);
+        assert!(left.is_left());
+        assert!(!left.is_right());
+
+        let right: Either<i32, String> = Either::Right(String::from("one"));
+        assert!(!right.is_left());
+        assert!(right.is_right());
+    }
+
+    #[test]
+    fn map_left_transforms_left() {
+        let left: Either<i32, String> = Either::Left(20);
+        assert_eq!(left.map_left(|n| n + 1), Either::Left(21));
+    }
+
+    #[test]
+    fn map_left_keeps_right() {
+        let right: Either<i32, String> = Either::Right(String::from("one"));
+        assert_eq!(
+            right.map_left(|n| n + 1),
+            Either::Right(String::from("one")),
+        );
+    }
+}
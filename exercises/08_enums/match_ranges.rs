@@ -0,0 +1,58 @@
+// Patterns in `match` arms aren't limited to enum variants and single values.
+// They can also match a whole range of values.
+//
+// Hint: The inclusive range pattern `a..=b` matches every value from `a` up to
+// and including `b`. Arms are checked from top to bottom, and the catch-all `_`
+// handles everything that no other arm matched.
+
+// TODO: Map the score to a grade:
+// - 90 to 100 -> 'A'
+// - 80 to 89  -> 'B'
+// - 70 to 79  -> 'C'
+// - 60 to 69  -> 'D'
+// - 0 to 59   -> 'F'
+// A score above 100 is invalid and should result in '?'.
+fn grade(score: u32) -> char {
+    match score {
+        90..=100 => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        0..=59 => 'F',
+        _ => '?',
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(grade(89), 'B');
+        assert_eq!(grade(90), 'A');
+        assert_eq!(grade(100), 'A');
+        assert_eq!(grade(59), 'F');
+        assert_eq!(grade(60), 'D');
+        assert_eq!(grade(0), 'F');
+    }
+
+    #[test]
+    fn midpoints() {
+        assert_eq!(grade(95), 'A');
+        assert_eq!(grade(85), 'B');
+        assert_eq!(grade(75), 'C');
+        assert_eq!(grade(65), 'D');
+        assert_eq!(grade(30), 'F');
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(grade(101), '?');
+        assert_eq!(grade(1000), '?');
+    }
+}
@@ -0,0 +1,52 @@
+// Sometimes, you only want to know whether a value matches a pattern without
+// extracting anything from it. Writing a whole `match` with `true` and `false`
+// arms for that is quite verbose.
+//
+// Hint: `matches!(value, pattern)` evaluates to `true` if `value` matches
+// `pattern` and to `false` otherwise. The pattern can use everything a `match`
+// arm can, like `|` to combine patterns or ranges like `a..=b`.
+
+// TODO: Use the `matches!` macro to check if `c` is a lowercase or uppercase
+// vowel (a, e, i, o, u).
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+// TODO: Use the `matches!` macro with a range pattern to check if `n` is
+// between 1 and 100 (both included).
+fn in_range(n: i32) -> bool {
+    matches!(n, 1..=100)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vowels() {
+        for c in ['a', 'e', 'i', 'o', 'u', 'A', 'E', 'I', 'O', 'U'] {
+            assert!(is_vowel(c));
+        }
+    }
+
+    #[test]
+    fn consonants() {
+        for c in ['b', 'z', 'Y', 'x', ' ', '1'] {
+            assert!(!is_vowel(c));
+        }
+    }
+
+    #[test]
+    fn range_boundaries() {
+        assert!(!in_range(0));
+        assert!(in_range(1));
+        assert!(in_range(50));
+        assert!(in_range(100));
+        assert!(!in_range(101));
+        assert!(!in_range(-5));
+    }
+}
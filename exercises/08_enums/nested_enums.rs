@@ -0,0 +1,54 @@
+// Enum variants can hold other enums. A single `match` arm can then look into
+// several layers at once by nesting the patterns.
+//
+// Hint: A struct-like variant is destructured by naming its fields, e.g.
+// `Shape::Rect { w, h }`. Such a pattern can be nested inside another one like
+// `Message::Draw(Shape::Rect { w, h })`.
+
+enum Shape {
+    Circle { r: f64 },
+    Rect { w: f64, h: f64 },
+}
+
+enum Message {
+    Draw(Shape),
+    Clear,
+}
+
+// TODO: Return a description of what happens for each message:
+// - Drawing a circle: "circle with radius R"
+// - Drawing a rectangle: "rectangle of W x H"
+// - Clearing: "clear"
+fn handle(msg: Message) -> String {
+    match msg {
+        Message::Draw(Shape::Circle { r }) => format!("circle with radius {r}"),
+        Message::Draw(Shape::Rect { w, h }) => format!("rectangle of {w} x {h}"),
+        Message::Clear => String::from("clear"),
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_circle() {
+        let msg = Message::Draw(Shape::Circle { r: 1.5 });
+        assert_eq!(handle(msg), "circle with radius 1.5");
+    }
+
+    #[test]
+    fn draw_rect() {
+        let msg = Message::Draw(Shape::Rect { w: 2.0, h: 3.5 });
+        assert_eq!(handle(msg), "rectangle of 2 x 3.5");
+    }
+
+    #[test]
+    fn clear() {
+        assert_eq!(handle(Message::Clear), "clear");
+    }
+}
@@ -0,0 +1,57 @@
+// Different values often need the same handling in a `match`. Instead of
+// repeating an arm for each of them, one arm can list several patterns.
+//
+// Hint: `A | B` matches if either `A` or `B` matches. It can be combined with
+// other patterns, e.g. `1 | 3 | 5..=9`, and works in `matches!` too.
+
+// TODO: Return `true` for "Sat" and "Sun" using an or-pattern.
+fn is_weekend(day: &str) -> bool {
+    matches!(day, "Sat" | "Sun")
+}
+
+// TODO: Classify `n`:
+// - 0 -> "zero"
+// - 1, 4, 9 -> "square"
+// - 2, 3, 5, 7 -> "prime"
+// - everything else -> "other"
+fn classify(n: i32) -> &'static str {
+    match n {
+        0 => "zero",
+        1 | 4 | 9 => "square",
+        2 | 3 | 5 | 7 => "prime",
+        _ => "other",
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekend() {
+        assert!(is_weekend("Sat"));
+        assert!(is_weekend("Sun"));
+    }
+
+    #[test]
+    fn weekday() {
+        for day in ["Mon", "Tue", "Wed", "Thu", "Fri"] {
+            assert!(!is_weekend(day));
+        }
+    }
+
+    #[test]
+    fn classes() {
+        assert_eq!(classify(0), "zero");
+        assert_eq!(classify(1), "square");
+        assert_eq!(classify(9), "square");
+        assert_eq!(classify(2), "prime");
+        assert_eq!(classify(7), "prime");
+        assert_eq!(classify(6), "other");
+        assert_eq!(classify(-4), "other");
+    }
+}
@@ -0,0 +1,51 @@
+// `match` can also look at the shape of a slice: how many elements it has and
+// what the first or last ones are.
+//
+// Hint: `[]` matches an empty slice and `[x]` a slice with exactly one element.
+// The rest pattern `..` matches any number of elements, so `[first, .., last]`
+// binds the first and last element of a slice with at least two elements.
+// Arms are checked in order, so put `[a, b]` before it.
+
+// TODO: Describe the slice:
+// - empty -> "empty"
+// - one element -> "one: X"
+// - two elements -> "two: A and B"
+// - more elements -> "many: from FIRST to LAST"
+fn describe(v: &[i32]) -> String {
+    match v {
+        [] => String::from("empty"),
+        [x] => format!("one: {x}"),
+        [a, b] => format!("two: {a} and {b}"),
+        [first, .., last] => format!("many: from {first} to {last}"),
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(describe(&[]), "empty");
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(describe(&[7]), "one: 7");
+    }
+
+    #[test]
+    fn two() {
+        assert_eq!(describe(&[1, 2]), "two: 1 and 2");
+    }
+
+    #[test]
+    fn many() {
+        assert_eq!(describe(&[1, 2, 3]), "many: from 1 to 3");
+        assert_eq!(describe(&[5, 0, 0, 0, -5]), "many: from 5 to -5");
+    }
+}
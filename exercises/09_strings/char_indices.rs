@@ -0,0 +1,43 @@
+// Strings in Rust are UTF-8 encoded. A single character can take up to 4
+// bytes, so the n-th character of a string doesn't necessarily start at byte n.
+//
+// Hint: `char_indices()` yields `(byte_index, char)` pairs. The byte index is
+// where the character starts in the string, which is what you need for
+// slicing. Like every iterator, it supports `nth(n)`.
+
+// TODO: Return the byte index at which the `n`-th character (starting at 0)
+// of `s` begins, or `None` if `s` has fewer characters.
+fn byte_index_of_nth_char(s: &str, n: usize) -> Option<usize> {
+    s.char_indices().nth(n).map(|(index, _)| index)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii() {
+        assert_eq!(byte_index_of_nth_char("hello", 0), Some(0));
+        assert_eq!(byte_index_of_nth_char("hello", 4), Some(4));
+    }
+
+    #[test]
+    fn multibyte() {
+        // 'ж' takes 2 bytes and '🦀' takes 4 bytes.
+        let s = "жж🦀x";
+        assert_eq!(byte_index_of_nth_char(s, 1), Some(2));
+        assert_eq!(byte_index_of_nth_char(s, 2), Some(4));
+        assert_eq!(byte_index_of_nth_char(s, 3), Some(8));
+        assert_eq!(&s[8..], "x");
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(byte_index_of_nth_char("жж", 2), None);
+        assert_eq!(byte_index_of_nth_char("", 0), None);
+    }
+}
@@ -0,0 +1,39 @@
+// A `String` can be collected from an iterator, just like a `Vec`. This makes
+// it easy to build a new string from the characters of another one.
+//
+// Hint: `String` implements `FromIterator<char>`, so `chars().filter(...)`
+// followed by `collect::<String>()` (or `collect()` with a `String` return
+// type) puts the remaining characters into a new string.
+
+// TODO: Return only the vowels (a, e, i, o, u in lower or upper case) of `s`.
+fn vowels_only(s: &str) -> String {
+    s.chars()
+        .filter(|c| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u'))
+        .collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed() {
+        assert_eq!(vowels_only("Hello, World!"), "eoo");
+        assert_eq!(vowels_only("Rustacean"), "uaea");
+        assert_eq!(vowels_only("AEIOU xyz"), "AEIOU");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(vowels_only(""), "");
+    }
+
+    #[test]
+    fn no_vowels() {
+        assert_eq!(vowels_only("rhythm"), "");
+    }
+}
@@ -0,0 +1,43 @@
+// `format!` creates a new `String` every time it is called. To build a longer
+// text piece by piece, you can instead write directly into one `String` with
+// the `write!` and `writeln!` macros.
+//
+// Hint: `write!` calls the `write_fmt` method of the `std::fmt::Write` trait,
+// which `String` implements. The trait has to be imported with
+// `use std::fmt::Write;` for this to compile. Writing into a `String` never
+// fails, so the returned `Result` can be unwrapped.
+
+use std::fmt::Write;
+
+// TODO: Write one line "NAME: COUNT" for every item into the report.
+fn build_report(items: &[(&str, i32)]) -> String {
+    let mut report = String::new();
+
+    for (name, count) in items {
+        writeln!(report, "{name}: {count}").unwrap();
+    }
+
+    report
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_line() {
+        assert_eq!(
+            build_report(&[("apples", 3), ("pears", 0), ("plums", -1)]),
+            "apples: 3\npears: 0\nplums: -1\n",
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(build_report(&[]), "");
+    }
+}
@@ -0,0 +1,40 @@
+// The formatting macros can print integers in other bases than decimal without
+// any manual conversion.
+//
+// Hint: `{:b}` formats a number in binary, `{:o}` in octal and `{:x}` in
+// lowercase hexadecimal (`{:X}` for uppercase). The `#` flag adds a prefix,
+// e.g. `format!("{:#x}", 255)` is "0xff".
+
+// TODO: Return the binary representation of `n` without a prefix.
+fn to_binary(n: u32) -> String {
+    format!("{n:b}")
+}
+
+// TODO: Return the lowercase hexadecimal representation of `n` without a
+// prefix.
+fn to_hex(n: u32) -> String {
+    format!("{n:x}")
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary() {
+        assert_eq!(to_binary(255), "11111111");
+        assert_eq!(to_binary(5), "101");
+        assert_eq!(to_binary(0), "0");
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(to_hex(255), "ff");
+        assert_eq!(to_hex(4096), "1000");
+        assert_eq!(to_hex(0), "0");
+    }
+}
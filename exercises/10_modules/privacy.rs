@@ -0,0 +1,48 @@
+// Making a struct public with `pub struct` doesn't make its fields public.
+// Code outside of the module can only reach private fields through public
+// methods, which lets the module control how the data is created and changed.
+//
+// Hint: Keep the `balance` field private and mark the constructor and the
+// getter with `pub`. Outside of `bank`, an `Account` can then only be created
+// with `Account::new` and its balance only be read through `balance()`.
+
+mod bank {
+    pub struct Account {
+        balance: u32,
+    }
+
+    impl Account {
+        // TODO: Make this constructor usable outside of the module.
+        pub fn new(balance: u32) -> Self {
+            Self { balance }
+        }
+
+        // TODO: Make this getter usable outside of the module.
+        pub fn balance(&self) -> u32 {
+            self.balance
+        }
+    }
+}
+
+use bank::Account;
+
+fn open_and_check(initial: u32) -> u32 {
+    // `Account { balance: initial }` wouldn't compile here.
+    let account = Account::new(initial);
+    account.balance()
+}
+
+fn main() {
+    println!("Balance: {}", open_and_check(100));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_through_getter() {
+        assert_eq!(open_and_check(100), 100);
+        assert_eq!(Account::new(0).balance(), 0);
+    }
+}
@@ -0,0 +1,61 @@
+// Deeply nested modules lead to long paths. A module can re-export items from
+// its submodules to offer a flatter public path, and `use` can give an item a
+// different local name.
+//
+// Hint: `pub use inner::item;` makes `item` available as `outer::item` for
+// code outside of `outer`, even if `inner` itself is private. `use path::item
+// as other;` brings `item` into scope under the name `other`.
+
+mod shapes {
+    mod geometry {
+        pub mod circle {
+            pub fn area(radius: f64) -> f64 {
+                std::f64::consts::PI * radius * radius
+            }
+
+            pub fn perimeter(radius: f64) -> f64 {
+                2.0 * std::f64::consts::PI * radius
+            }
+        }
+    }
+
+    // TODO: Re-export the functions of the `circle` module so that they can be
+    // used as `shapes::area` and `shapes::perimeter`.
+    pub use geometry::circle::{area, perimeter};
+}
+
+// TODO: Bring `shapes::area` into scope and `shapes::perimeter` under the name
+// `circumference`.
+use shapes::{area, perimeter as circumference};
+
+fn describe_circle(radius: f64) -> String {
+    format!(
+        "area: {:.2}, circumference: {:.2}",
+        area(radius),
+        circumference(radius),
+    )
+}
+
+fn main() {
+    println!("{}", describe_circle(1.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_circle() {
+        assert_eq!(describe_circle(1.0), "area: 3.14, circumference: 6.28");
+    }
+
+    #[test]
+    fn larger_circle() {
+        assert_eq!(describe_circle(2.5), "area: 19.63, circumference: 15.71");
+    }
+
+    #[test]
+    fn flat_path() {
+        assert_eq!(shapes::area(0.0), 0.0);
+    }
+}
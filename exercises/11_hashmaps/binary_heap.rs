@@ -0,0 +1,48 @@
+// A `BinaryHeap` is a collection that always gives you its largest element
+// first. This makes it a good fit for finding the "top k" elements.
+//
+// Hint: `BinaryHeap` is a max-heap: `pop()` removes and returns the largest
+// element. Its elements only need to implement `Ord`. Wrapping them in
+// `std::cmp::Reverse` flips the order and turns it into a min-heap.
+
+use std::collections::BinaryHeap;
+
+// TODO: Return the `k` largest elements of `v` in descending order. If `v` has
+// fewer than `k` elements, return all of them.
+fn k_largest(v: &[i32], k: usize) -> Vec<i32> {
+    let mut heap: BinaryHeap<i32> = v.iter().copied().collect();
+
+    let mut result = Vec::with_capacity(k.min(v.len()));
+    while result.len() < k {
+        let Some(largest) = heap.pop() else {
+            break;
+        };
+        result.push(largest);
+    }
+
+    result
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_descending() {
+        assert_eq!(k_largest(&[5, 1, 9, 3, 7, 9], 3), [9, 9, 7]);
+    }
+
+    #[test]
+    fn k_larger_than_len() {
+        assert_eq!(k_largest(&[2, -1, 4], 10), [4, 2, -1]);
+    }
+
+    #[test]
+    fn k_zero() {
+        assert!(k_largest(&[1, 2, 3], 0).is_empty());
+    }
+}
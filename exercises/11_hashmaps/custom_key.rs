@@ -0,0 +1,65 @@
+// The keys of a hash map don't have to be strings or numbers. Your own types
+// can be used as keys too, as long as they can be hashed and compared.
+//
+// Hint: `HashMap` requires its keys to implement `Eq` and `Hash`. Both (and
+// `PartialEq`, which `Eq` builds on) can be derived for a struct whose fields
+// implement them.
+
+use std::collections::HashMap;
+
+// TODO: Add the derives needed to use `Coord` as a hash map key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Coord {
+    x: i32,
+    y: i32,
+}
+
+// TODO: Count how many times every coordinate was visited.
+fn count_visits(path: &[Coord]) -> HashMap<Coord, u32> {
+    let mut visits = HashMap::new();
+
+    for &coord in path {
+        *visits.entry(coord).or_insert(0) += 1;
+    }
+
+    visits
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_coords() {
+        let path = [
+            Coord { x: 0, y: 0 },
+            Coord { x: 1, y: 0 },
+            Coord { x: 0, y: 0 },
+            Coord { x: 0, y: 0 },
+        ];
+        let visits = count_visits(&path);
+        assert_eq!(visits[&Coord { x: 0, y: 0 }], 3);
+        assert_eq!(visits[&Coord { x: 1, y: 0 }], 1);
+    }
+
+    #[test]
+    fn distinct_coords() {
+        let path = [
+            Coord { x: 1, y: 2 },
+            Coord { x: 2, y: 1 },
+            Coord { x: -1, y: 2 },
+        ];
+        let visits = count_visits(&path);
+        assert_eq!(visits.len(), 3);
+        assert!(visits.values().all(|&count| count == 1));
+    }
+
+    #[test]
+    fn empty_path() {
+        assert!(count_visits(&[]).is_empty());
+    }
+}
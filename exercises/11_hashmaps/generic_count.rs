@@ -0,0 +1,50 @@
+// Counting how often every element occurs works the same for characters,
+// strings and many other types. So let's write the counting function once as
+// a generic function.
+//
+// Hint: A type can only be a `HashMap` key if it implements `Eq` and `Hash`.
+// Since the map owns its keys, the elements borrowed from the slice also have
+// to be cloned, which needs `Clone`.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// TODO: Add the required trait bounds and count how often every item occurs.
+fn frequencies<T: Eq + Hash + Clone>(items: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+
+    for item in items {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chars() {
+        let counts = frequencies(&['a', 'b', 'a', 'c', 'a']);
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&'a'], 3);
+        assert_eq!(counts[&'b'], 1);
+        assert_eq!(counts[&'c'], 1);
+    }
+
+    #[test]
+    fn str_slices() {
+        let counts = frequencies(&["red", "green", "red"]);
+        assert_eq!(counts, HashMap::from([("red", 2), ("green", 1)]));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(frequencies::<i32>(&[]).is_empty());
+    }
+}
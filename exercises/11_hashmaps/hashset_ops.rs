@@ -0,0 +1,50 @@
+// A `HashSet` is like a `HashMap` without values. It stores every element at
+// most once and supports set operations like union and intersection.
+//
+// Hint: `HashSet::intersection` returns an iterator over the elements that are
+// in both sets. Just like iterating over a hash map, the order is not
+// deterministic, so sort the result before comparing it.
+
+use std::collections::HashSet;
+
+// TODO: Return the number of distinct elements in `v`.
+fn unique(v: &[i32]) -> usize {
+    v.iter().collect::<HashSet<_>>().len()
+}
+
+// TODO: Return the elements that appear in both `a` and `b`, sorted in
+// ascending order and without duplicates.
+fn common(a: &[i32], b: &[i32]) -> Vec<i32> {
+    let a: HashSet<i32> = a.iter().copied().collect();
+    let b: HashSet<i32> = b.iter().copied().collect();
+
+    let mut result: Vec<i32> = a.intersection(&b).copied().collect();
+    result.sort();
+    result
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_unique() {
+        assert_eq!(unique(&[1, 2, 2, 3, 3, 3]), 3);
+        assert_eq!(unique(&[5]), 1);
+        assert_eq!(unique(&[]), 0);
+    }
+
+    #[test]
+    fn sorted_intersection() {
+        assert_eq!(common(&[5, 1, 3, 3, 7], &[7, 3, 9, 1]), [1, 3, 7]);
+    }
+
+    #[test]
+    fn disjoint_slices() {
+        assert!(common(&[1, 2, 3], &[4, 5, 6]).is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+// A collection of `Option`s can be handled in two different ways: either by
+// dropping all `None`s, or by treating a single `None` as a failure of the
+// whole collection.
+//
+// Hint: `Option` implements `IntoIterator`. It yields one element for `Some`
+// and none for `None`, so `flatten()` keeps only the inner values. Collecting
+// an iterator of `Option<T>` into `Option<Vec<T>>` returns `None` as soon as a
+// `None` is encountered.
+
+// TODO: Return all values inside `Some`, dropping the `None`s.
+fn keep_some(v: Vec<Option<i32>>) -> Vec<i32> {
+    v.into_iter().flatten().collect()
+}
+
+// TODO: Return all values if every element is `Some`, otherwise `None`.
+fn all_some(v: &[Option<i32>]) -> Option<Vec<i32>> {
+    v.iter().copied().collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_drops_none() {
+        assert_eq!(keep_some(vec![Some(1), None, Some(3), None]), [1, 3]);
+        assert!(keep_some(vec![None, None]).is_empty());
+    }
+
+    #[test]
+    fn collect_all_some() {
+        assert_eq!(all_some(&[Some(1), Some(2), Some(3)]), Some(vec![1, 2, 3]));
+        assert_eq!(all_some(&[]), Some(vec![]));
+    }
+
+    #[test]
+    fn collect_with_one_none() {
+        assert_eq!(all_some(&[Some(1), None, Some(3)]), None);
+    }
+}
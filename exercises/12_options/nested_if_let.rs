@@ -0,0 +1,50 @@
+// Looking up a value in nested collections can fail at every level. Instead of
+// nesting `if let` or `match` for each level, the `?` operator (or
+// `and_then`) chains the lookups and stops at the first `None`.
+//
+// Hint: `map.get(key)` returns `Option<&Vec<i32>>` and `vec.get(idx)` returns
+// `Option<&i32>`. Inside a function returning `Option`, `?` unwraps a `Some`
+// or returns `None` right away.
+
+use std::collections::HashMap;
+
+// TODO: Return the element at `idx` of the vector stored under `key`, or
+// `None` if the key or the index doesn't exist.
+fn lookup(map: &HashMap<String, Vec<i32>>, key: &str, idx: usize) -> Option<i32> {
+    let values = map.get(key)?;
+    let value = values.get(idx)?;
+    Some(*value)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data() -> HashMap<String, Vec<i32>> {
+        HashMap::from([
+            (String::from("primes"), vec![2, 3, 5, 7]),
+            (String::from("empty"), vec![]),
+        ])
+    }
+
+    #[test]
+    fn present_key_valid_index() {
+        assert_eq!(lookup(&data(), "primes", 0), Some(2));
+        assert_eq!(lookup(&data(), "primes", 3), Some(7));
+    }
+
+    #[test]
+    fn present_key_invalid_index() {
+        assert_eq!(lookup(&data(), "primes", 4), None);
+        assert_eq!(lookup(&data(), "empty", 0), None);
+    }
+
+    #[test]
+    fn missing_key() {
+        assert_eq!(lookup(&data(), "squares", 0), None);
+    }
+}
@@ -0,0 +1,73 @@
+// Instead of panicking with `unwrap()` when there is no value, you can fall
+// back to a default. `Option` and `Result` both offer `unwrap_or` and
+// `unwrap_or_else` for that.
+//
+// Hint: The argument of `unwrap_or` is evaluated *before* the call, even if
+// it isn't needed (eager). `unwrap_or_else` takes a closure that is only called
+// if there is no value (lazy). Prefer it when computing the default is costly.
+
+use std::cell::Cell;
+
+thread_local! {
+    // Counts how often `expensive_default` was called.
+    static FALLBACK_CALLS: Cell<u32> = const { Cell::new(0) };
+}
+
+// Pretend that this function takes a long time to compute the default value.
+// Don't change this function.
+fn expensive_default() -> i32 {
+    FALLBACK_CALLS.with(|calls| calls.set(calls.get() + 1));
+    -1
+}
+
+// TODO: Parse `s` as a number. Return 0 if this fails by using `unwrap_or`.
+fn parse_or_default(s: &str) -> i32 {
+    s.parse().unwrap_or(0)
+}
+
+// TODO: Parse `s` as a number. If this fails, return the value of
+// `expensive_default()` by using `unwrap_or_else`. The function must only be
+// called if parsing fails.
+fn parse_or_compute(s: &str) -> i32 {
+    s.parse().unwrap_or_else(|_| expensive_default())
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fallback_calls() -> u32 {
+        FALLBACK_CALLS.with(Cell::get)
+    }
+
+    #[test]
+    fn default_on_valid_input() {
+        assert_eq!(parse_or_default("42"), 42);
+        assert_eq!(parse_or_default("-7"), -7);
+    }
+
+    #[test]
+    fn default_on_invalid_input() {
+        assert_eq!(parse_or_default("abc"), 0);
+        assert_eq!(parse_or_default(""), 0);
+    }
+
+    #[test]
+    fn compute_on_valid_input() {
+        assert_eq!(parse_or_compute("42"), 42);
+        // The fallback wasn't needed.
+        assert_eq!(fallback_calls(), 0);
+    }
+
+    #[test]
+    fn compute_on_invalid_input() {
+        assert_eq!(parse_or_compute("abc"), -1);
+        assert_eq!(fallback_calls(), 1);
+        assert_eq!(parse_or_compute("12"), 12);
+        assert_eq!(fallback_calls(), 1);
+    }
+}
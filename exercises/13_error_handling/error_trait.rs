@@ -0,0 +1,100 @@
+// Implementing `std::error::Error` for a custom error type makes it play well
+// with the rest of the ecosystem (e.g. it can be boxed into `Box<dyn Error>`).
+// An error can also point to a lower-level error that caused it by returning
+// it from `source()`.
+//
+// Hint: `Error` requires `Debug` and `Display`. `Error::source` has a default
+// implementation returning `None`, so you only need to override it for the
+// variant that wraps another error. This is how error chains are built.
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+enum ValidationError {
+    Empty,
+    TooLarge(u32),
+    NotANumber(ParseIntError),
+}
+
+// TODO: Implement `Display` so that each variant has a readable message.
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "input is empty"),
+            Self::TooLarge(value) => write!(f, "{value} is larger than 100"),
+            Self::NotANumber(_) => write!(f, "input is not a number"),
+        }
+    }
+}
+
+// TODO: Implement `Error` and return the inner error from `source()` for the
+// `NotANumber` variant.
+impl Error for ValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotANumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+fn validate(input: &str) -> Result<u32, ValidationError> {
+    if input.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+
+    let value: u32 = input.parse().map_err(ValidationError::NotANumber)?;
+    if value > 100 {
+        return Err(ValidationError::TooLarge(value));
+    }
+
+    Ok(value)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_input() {
+        assert_eq!(validate("42").unwrap(), 42);
+    }
+
+    #[test]
+    fn display_messages() {
+        assert_eq!(validate("").unwrap_err().to_string(), "input is empty");
+        assert_eq!(
+            validate("200").unwrap_err().to_string(),
+            "200 is larger than 100",
+        );
+        assert_eq!(
+            validate("abc").unwrap_err().to_string(),
+            "input is not a number",
+        );
+    }
+
+    #[test]
+    fn source_of_parse_error() {
+        let err = validate("abc").unwrap_err();
+        let source = err.source().expect("expected an underlying error");
+        assert_eq!(source.to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn no_source_for_other_errors() {
+        assert!(validate("").unwrap_err().source().is_none());
+        assert!(validate("200").unwrap_err().source().is_none());
+    }
+
+    #[test]
+    fn boxed_error() {
+        let err: Box<dyn Error> = Box::new(validate("").unwrap_err());
+        assert_eq!(err.to_string(), "input is empty");
+    }
+}
@@ -0,0 +1,48 @@
+// Imagine that `contents` was read from a file that contains one number per
+// line. We want to sum all numbers, but parsing a line can fail. Instead of
+// handling every error in place, we propagate it to the caller.
+//
+// Hint: `str::lines` returns an iterator over the lines of a string. The `?`
+// operator converts every error that implements `Error` into a
+// `Box<dyn Error>` for you.
+
+use std::error::Error;
+
+// TODO: Parse every line of `contents` as an `i32` and return the sum.
+// Propagate parsing errors with the `?` operator.
+fn sum_file_numbers(contents: &str) -> Result<i32, Box<dyn Error>> {
+    let mut sum = 0;
+
+    for line in contents.lines() {
+        let number: i32 = line.trim().parse()?;
+        sum += number;
+    }
+
+    Ok(sum)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_formed() {
+        assert_eq!(sum_file_numbers("1\n2\n3\n-4\n").unwrap(), 2);
+        assert_eq!(sum_file_numbers(" 10 \n20").unwrap(), 30);
+    }
+
+    #[test]
+    fn invalid_line() {
+        let err = sum_file_numbers("1\ntwo\n3").unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sum_file_numbers("").unwrap(), 0);
+    }
+}
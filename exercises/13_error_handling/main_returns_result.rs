@@ -0,0 +1,52 @@
+// Not only ordinary functions can use the `?` operator. `main` can too if it
+// returns a `Result`. If `main` returns an `Err`, the program prints the error
+// and exits with a non-zero exit code.
+//
+// Hint: `main` may return `Result<(), E>` for any error type `E` that
+// implements `Debug`. `Box<dyn Error>` is a convenient choice because `?` can
+// convert every error type into it.
+
+use std::error::Error;
+
+// Reads the port of a server from a configuration line like "port=8080".
+// Don't change this function.
+fn parse_port(line: &str) -> Result<u16, Box<dyn Error>> {
+    let value = line
+        .strip_prefix("port=")
+        .ok_or("the line doesn't start with `port=`")?;
+    let port = value.parse()?;
+    Ok(port)
+}
+
+// TODO: Change the signature and the body of `main` so that it propagates the
+// error returned by `parse_port` with the `?` operator.
+fn main() -> Result<(), Box<dyn Error>> {
+    let port = parse_port("port=8080")?;
+    println!("Listening on port {port}");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `main` is hard to test directly, so we test the helper instead.
+
+    #[test]
+    fn valid_port() {
+        assert_eq!(parse_port("port=8080").unwrap(), 8080);
+    }
+
+    #[test]
+    fn missing_prefix() {
+        let err = parse_port("8080").unwrap_err();
+        assert_eq!(err.to_string(), "the line doesn't start with `port=`");
+    }
+
+    #[test]
+    fn invalid_number() {
+        assert!(parse_port("port=http").is_err());
+        assert!(parse_port("port=70000").is_err());
+    }
+}
@@ -0,0 +1,58 @@
+// Trait bounds can also be put on an `impl` block. Its methods then only exist
+// for the types that satisfy the bounds, while the methods of other `impl`
+// blocks stay available for every type.
+//
+// Hint: `impl<T: Display + PartialOrd> Pair<T> { ... }` adds methods to
+// `Pair<i32>` or `Pair<String>`, but not to a `Pair` of a type that can't be
+// displayed or compared.
+
+use std::fmt::Display;
+
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+impl<T> Pair<T> {
+    fn new(a: T, b: T) -> Self {
+        Self { a, b }
+    }
+}
+
+// TODO: Add an `impl` block that is only available if `T` implements `Display`
+// and `PartialOrd`. Implement `cmp_display` in it, which returns a message
+// containing the larger of both elements.
+impl<T: Display + PartialOrd> Pair<T> {
+    fn cmp_display(&self) -> String {
+        if self.a >= self.b {
+            format!("The largest member is {}", self.a)
+        } else {
+            format!("The largest member is {}", self.b)
+        }
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn larger_first() {
+        assert_eq!(Pair::new(7, 3).cmp_display(), "The largest member is 7");
+    }
+
+    #[test]
+    fn larger_second() {
+        assert_eq!(Pair::new(-2, 5).cmp_display(), "The largest member is 5");
+    }
+
+    #[test]
+    fn strings() {
+        let pair = Pair::new(String::from("apple"), String::from("pear"));
+        assert_eq!(pair.cmp_display(), "The largest member is pear");
+    }
+}
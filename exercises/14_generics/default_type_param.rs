@@ -0,0 +1,53 @@
+// A generic type parameter can have a default type. If the type is written
+// without specifying the parameter, the default is used.
+//
+// Hint: Declare the default with `struct Wrapper<T = i32>`. It applies when
+// the type is *named* without a parameter, e.g. in `let w: Wrapper = ...;`.
+// It doesn't guide type inference, so `Wrapper::default()` alone still needs a
+// type annotation somewhere.
+
+// TODO: Give the generic parameter `T` the default type `i32`.
+#[derive(Debug, Default)]
+struct Wrapper<T = i32> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    // TODO: Return a reference to the wrapped value.
+    fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_type() {
+        // `Wrapper` without a parameter is `Wrapper<i32>`.
+        let w: Wrapper = Wrapper::default();
+        assert_eq!(*w.get(), 0);
+
+        let w: Wrapper = Wrapper::new(42);
+        let value: i32 = *w.get();
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn explicit_type() {
+        let w: Wrapper<String> = Wrapper::new(String::from("rust"));
+        assert_eq!(w.get(), "rust");
+
+        let w: Wrapper<String> = Wrapper::default();
+        assert!(w.get().is_empty());
+    }
+}
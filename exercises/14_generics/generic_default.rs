@@ -0,0 +1,55 @@
+// Trait bounds on a generic struct let its methods rely on the capabilities
+// of the generic type. Here, `Default` gives every `Buffer` a fallback value
+// without the caller having to provide one.
+//
+// Hint: With the bound `T: Default`, `T::default()` creates a value of any
+// `T`, like `0` for `i32` or an empty `String`. Store it in the struct so that
+// `get_or_fallback` can return a reference to it.
+
+struct Buffer<T: Default> {
+    items: Vec<T>,
+    fallback: T,
+}
+
+impl<T: Default> Buffer<T> {
+    fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            fallback: T::default(),
+        }
+    }
+
+    // TODO: Return the item at `idx`, or the fallback if `idx` is out of range.
+    fn get_or_fallback(&self, idx: usize) -> &T {
+        self.items.get(idx).unwrap_or(&self.fallback)
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let buffer = Buffer::new(vec![4, 8, 15]);
+        assert_eq!(*buffer.get_or_fallback(1), 8);
+        assert_eq!(*buffer.get_or_fallback(3), 0);
+    }
+
+    #[test]
+    fn strings() {
+        let buffer = Buffer::new(vec![String::from("a"), String::from("b")]);
+        assert_eq!(buffer.get_or_fallback(0), "a");
+        assert_eq!(buffer.get_or_fallback(10), "");
+    }
+
+    #[test]
+    fn empty() {
+        let buffer: Buffer<i32> = Buffer::new(Vec::new());
+        assert_eq!(*buffer.get_or_fallback(0), 0);
+    }
+}
@@ -0,0 +1,94 @@
+// A function that works with any type implementing a trait can be written in
+// two ways: with a generic parameter or with a trait object. Both accept the
+// same arguments, but they are compiled very differently.
+//
+// Hint: A generic function is monomorphized: the compiler generates a copy of
+// it for every concrete type it is called with. This makes calls fast, but
+// increases the binary size. A function taking `&dyn Trait` exists only once
+// and looks up the method at runtime (dynamic dispatch), which is a bit slower
+// but more flexible, e.g. for storing different types in one collection.
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+struct Article {
+    title: String,
+    author: String,
+}
+
+impl Summary for Article {
+    fn summarize(&self) -> String {
+        format!("{}, by {}", self.title, self.author)
+    }
+}
+
+struct Tweet {
+    username: String,
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("New tweet from @{}", self.username)
+    }
+}
+
+// TODO: Complete the signature using a generic parameter with a trait bound.
+fn notify_generic<T: Summary>(item: &T) -> String {
+    format!("Breaking news! {}", item.summarize())
+}
+
+// TODO: Complete the signature using a trait object.
+fn notify_dyn(item: &dyn Summary) -> String {
+    format!("Breaking news! {}", item.summarize())
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_behavior() {
+        let article = Article {
+            title: String::from("Rust 2024"),
+            author: String::from("Ferris"),
+        };
+        assert_eq!(
+            notify_generic(&article),
+            "Breaking news! Rust 2024, by Ferris",
+        );
+        assert_eq!(notify_generic(&article), notify_dyn(&article));
+
+        let tweet = Tweet {
+            username: String::from("rustlang"),
+        };
+        assert_eq!(notify_generic(&tweet), notify_dyn(&tweet));
+    }
+
+    #[test]
+    fn dyn_in_collection() {
+        let items: Vec<Box<dyn Summary>> = vec![
+            Box::new(Tweet {
+                username: String::from("rustlang"),
+            }),
+            Box::new(Article {
+                title: String::from("Rust 2024"),
+                author: String::from("Ferris"),
+            }),
+        ];
+
+        let notifications: Vec<String> =
+            items.iter().map(|item| notify_dyn(item.as_ref())).collect();
+        assert_eq!(
+            notifications,
+            [
+                "Breaking news! New tweet from @rustlang",
+                "Breaking news! Rust 2024, by Ferris",
+            ],
+        );
+    }
+}
@@ -0,0 +1,42 @@
+// A generic function can return a value of its generic type. Here, the result
+// is always one of the three arguments, so the function works for every type
+// whose values can be compared.
+//
+// Hint: The `PartialOrd` bound allows comparing values of type `T` with `<`
+// and `>`. Since the arguments are taken by value, you can return one of them
+// directly without cloning.
+
+// TODO: Return `lo` if `value` is smaller than `lo`, `hi` if `value` is larger
+// than `hi`, and `value` otherwise.
+fn clamp_generic<T: PartialOrd>(value: T, lo: T, hi: T) -> T {
+    if value < lo {
+        lo
+    } else if value > hi {
+        hi
+    } else {
+        value
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(clamp_generic(-5, 0, 10), 0);
+        assert_eq!(clamp_generic(15, 0, 10), 10);
+        assert_eq!(clamp_generic(7, 0, 10), 7);
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(clamp_generic(-0.5, 0.0, 1.0), 0.0);
+        assert_eq!(clamp_generic(1.5, 0.0, 1.0), 1.0);
+        assert_eq!(clamp_generic(0.25, 0.0, 1.0), 0.25);
+    }
+}
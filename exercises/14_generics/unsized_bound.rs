@@ -0,0 +1,36 @@
+// Every generic parameter has an implicit `Sized` bound, meaning that its size
+// has to be known at compile time. Types like `str` and `[T]` don't have a
+// known size, so they can't be used for such a parameter, not even behind a
+// reference.
+//
+// Hint: Adding `?Sized` to the bounds removes the implicit `Sized` bound. This
+// is fine as long as the parameter is only used behind a reference (or another
+// pointer like `Box`), which always has a known size.
+
+// TODO: Add a bound so that this function also accepts `str` as `T`.
+fn print_len<T: AsRef<str> + ?Sized>(s: &T) -> usize {
+    let len = s.as_ref().len();
+    println!("{len}");
+    len
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_slice() {
+        // Here, `T` is `str`.
+        assert_eq!(print_len("hello"), 5);
+    }
+
+    #[test]
+    fn string() {
+        // Here, `T` is `String`.
+        assert_eq!(print_len(&String::from("rustacean")), 9);
+    }
+}
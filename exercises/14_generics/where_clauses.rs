@@ -0,0 +1,46 @@
+// Trait bounds can be written directly after the generic parameter, like
+// `fn f<T: Display>(t: T)`, or in a separate `where` clause after the return
+// type.
+//
+// Hint: A `where` clause is handy once a generic parameter has multiple bounds
+// or there are multiple generic parameters. The signature then stays readable
+// because the name, parameters and return type aren't buried between bounds.
+
+use std::fmt::Display;
+
+// TODO: Add a `where` clause that requires `T` to implement `Display` and
+// `Clone`. Then join the displayed items with ", ".
+fn print_all<T>(items: &[T]) -> String
+where
+    T: Display + Clone,
+{
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(print_all(&[1, 2, 3]), "1, 2, 3");
+    }
+
+    #[test]
+    fn string_slices() {
+        assert_eq!(print_all(&["a", "bc", "def"]), "a, bc, def");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(print_all::<i32>(&[]), "");
+    }
+}
@@ -0,0 +1,45 @@
+// Traits can't only require methods, they can also require constants. Every
+// implementor then has to provide a value for each of them.
+//
+// Hint: An associated constant is declared in the trait with
+// `const NAME: Type;` and defined in the `impl` block with
+// `const NAME: Type = value;`. It is accessed through the type, e.g.
+// `Percent::MAX`.
+
+trait Bounded {
+    const MAX: Self;
+    const MIN: Self;
+}
+
+#[derive(Debug, PartialEq)]
+struct Percent(u8);
+
+// TODO: Define the constants. A percentage goes from 0 to 100.
+impl Bounded for Percent {
+    const MAX: Self = Percent(100);
+    const MIN: Self = Percent(0);
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        assert_eq!(Percent::MAX.0, 100);
+        assert_eq!(Percent::MIN.0, 0);
+    }
+
+    #[test]
+    fn through_trait() {
+        fn range<T: Bounded>() -> (T, T) {
+            (T::MIN, T::MAX)
+        }
+
+        assert_eq!(range::<Percent>(), (Percent(0), Percent(100)));
+    }
+}
@@ -0,0 +1,50 @@
+// `#[derive(PartialEq)]` compares all fields for exact equality. If two values
+// should be considered equal under different rules, implement `PartialEq` by
+// hand instead.
+//
+// Hint: `PartialEq` only requires the method `fn eq(&self, other: &Self) -> bool`.
+// `!=` is provided automatically. `str::eq_ignore_ascii_case` compares two
+// strings while ignoring the case of ASCII letters.
+
+#[derive(Debug)]
+struct CaseInsensitive(String);
+
+impl PartialEq for CaseInsensitive {
+    // TODO: Two values are equal if their strings only differ in letter case.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn different_case() {
+        assert_eq!(
+            CaseInsensitive(String::from("Hello")),
+            CaseInsensitive(String::from("hello")),
+        );
+        assert_eq!(
+            CaseInsensitive(String::from("RUST")),
+            CaseInsensitive(String::from("rUsT")),
+        );
+    }
+
+    #[test]
+    fn different_strings() {
+        assert_ne!(
+            CaseInsensitive(String::from("Hello")),
+            CaseInsensitive(String::from("World")),
+        );
+        assert_ne!(
+            CaseInsensitive(String::from("hello")),
+            CaseInsensitive(String::from("hello!")),
+        );
+    }
+}
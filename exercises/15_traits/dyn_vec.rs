@@ -0,0 +1,75 @@
+// A `Vec` can only hold elements of one type. To store values of different
+// types that all implement the same trait, box them as trait objects.
+//
+// Hint: `Vec<Box<dyn Component>>` is a vector of boxed trait objects. Each box
+// can hold a different concrete type, and calling `render` on an element is
+// dispatched at runtime to the implementation of the type inside the box.
+
+trait Component {
+    fn render(&self) -> String;
+}
+
+struct Label {
+    text: String,
+}
+
+impl Component for Label {
+    fn render(&self) -> String {
+        format!("[{}]", self.text)
+    }
+}
+
+struct Spacer {
+    width: usize,
+}
+
+impl Component for Spacer {
+    fn render(&self) -> String {
+        " ".repeat(self.width)
+    }
+}
+
+struct Screen {
+    // TODO: Store components of any type that implements `Component`.
+    components: Vec<Box<dyn Component>>,
+}
+
+impl Screen {
+    // TODO: Concatenate the output of `render` of all components in order.
+    fn render_all(&self) -> String {
+        self.components.iter().map(|c| c.render()).collect()
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_components() {
+        let screen = Screen {
+            components: vec![
+                Box::new(Label {
+                    text: String::from("OK"),
+                }),
+                Box::new(Spacer { width: 2 }),
+                Box::new(Label {
+                    text: String::from("Cancel"),
+                }),
+            ],
+        };
+        assert_eq!(screen.render_all(), "[OK]  [Cancel]");
+    }
+
+    #[test]
+    fn empty_screen() {
+        let screen = Screen {
+            components: Vec::new(),
+        };
+        assert_eq!(screen.render_all(), "");
+    }
+}
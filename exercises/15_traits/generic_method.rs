@@ -0,0 +1,42 @@
+// Not only functions can be generic, trait methods can be too. Every call of a
+// generic method can use a different type for its generic parameter.
+//
+// Hint: Every closure has its own unique type. If the trait had an associated
+// type for the closure instead, each implementor could only support a single
+// closure type. A generic method accepts any `F: Fn(i32) -> i32` per call.
+
+trait Mapper {
+    fn map_all<F: Fn(i32) -> i32>(&self, f: F) -> Vec<i32>;
+}
+
+struct Numbers(Vec<i32>);
+
+// TODO: Implement `map_all` so that it applies `f` to every number and
+// returns the results.
+impl Mapper for Numbers {
+    fn map_all<F: Fn(i32) -> i32>(&self, f: F) -> Vec<i32> {
+        self.0.iter().map(|&n| f(n)).collect()
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doubling() {
+        let numbers = Numbers(vec![1, 2, 3]);
+        assert_eq!(numbers.map_all(|n| n * 2), [2, 4, 6]);
+    }
+
+    #[test]
+    fn incrementing() {
+        let numbers = Numbers(vec![-1, 0, 41]);
+        let step = 1;
+        assert_eq!(numbers.map_all(|n| n + step), [0, 1, 42]);
+    }
+}
@@ -0,0 +1,52 @@
+// `Iterator::sum` isn't limited to numbers. It works for every type that
+// implements the `std::iter::Sum` trait, including your own types.
+//
+// Hint: `Sum<A>` has one method, `fn sum<I: Iterator<Item = A>>(iter: I) -> Self`.
+// Implement `Sum<Self>` for iterators over owned values (`into_iter()`) and
+// `Sum<&'a Self>` for iterators over references (`iter()`).
+
+use std::iter::Sum;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Money(i64);
+
+// TODO: Implement `Sum` so that an iterator over `Money` values can be summed.
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Money(iter.map(|money| money.0).sum())
+    }
+}
+
+// TODO: Implement `Sum` so that an iterator over `&Money` can be summed too.
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_owned() {
+        let wallet = vec![Money(100), Money(-30), Money(5)];
+        assert_eq!(wallet.into_iter().sum::<Money>(), Money(75));
+    }
+
+    #[test]
+    fn sum_references() {
+        let wallet = [Money(1), Money(2), Money(3)];
+        assert_eq!(wallet.iter().sum::<Money>(), Money(6));
+    }
+
+    #[test]
+    fn sum_empty() {
+        assert_eq!(Vec::<Money>::new().into_iter().sum::<Money>(), Money(0));
+        assert_eq!(std::iter::empty::<&Money>().sum::<Money>(), Money(0));
+    }
+}
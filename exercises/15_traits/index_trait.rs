@@ -0,0 +1,52 @@
+// The indexing syntax `container[index]` isn't reserved for arrays, slices
+// and vectors. Any type can support it by implementing the `Index` trait. The
+// index doesn't have to be a number either, it can be a tuple for example.
+//
+// Hint: `Index<Idx>` has an associated type `Output` which is the type of the
+// element returned by reference from `index`. `m[(row, col)]` is then
+// translated to `*m.index((row, col))`.
+
+use std::ops::Index;
+
+struct Matrix2x2 {
+    data: [[i32; 2]; 2],
+}
+
+impl Index<(usize, usize)> for Matrix2x2 {
+    type Output = i32;
+
+    // TODO: Return a reference to the element in row `index.0` and column
+    // `index.1`.
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.data[index.0][index.1]
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_elements() {
+        let m = Matrix2x2 {
+            data: [[1, 2], [3, 4]],
+        };
+        assert_eq!(m[(0, 0)], 1);
+        assert_eq!(m[(0, 1)], 2);
+        assert_eq!(m[(1, 0)], 3);
+        assert_eq!(m[(1, 1)], 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let m = Matrix2x2 {
+            data: [[1, 2], [3, 4]],
+        };
+        let _ = m[(2, 0)];
+    }
+}
@@ -0,0 +1,51 @@
+// `Clone` is usually derived, but it is an ordinary trait that you can also
+// implement yourself. Writing the implementation by hand shows what `clone()`
+// actually does for a type that owns heap data.
+//
+// Hint: A `Vec` owns its elements on the heap. Cloning it allocates a new
+// buffer and copies every element into it (a deep copy). The clone and the
+// original don't share anything afterwards.
+
+#[derive(Debug, PartialEq)]
+struct Playlist {
+    track_ids: Vec<i32>,
+}
+
+// TODO: Implement `Clone` without using `#[derive(Clone)]`.
+impl Clone for Playlist {
+    fn clone(&self) -> Self {
+        Self {
+            track_ids: self.track_ids.clone(),
+        }
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_is_equal() {
+        let playlist = Playlist {
+            track_ids: vec![3, 1, 4],
+        };
+        assert_eq!(playlist.clone(), playlist);
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let original = Playlist {
+            track_ids: vec![3, 1, 4],
+        };
+        let mut copy = original.clone();
+        copy.track_ids.push(1);
+        copy.track_ids[0] = 9;
+
+        assert_eq!(original.track_ids, [3, 1, 4]);
+        assert_eq!(copy.track_ids, [9, 1, 4, 1]);
+    }
+}
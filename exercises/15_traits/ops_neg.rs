@@ -0,0 +1,59 @@
+// Unary operators can be overloaded too: `-x` calls `Neg::neg` and `!x` calls
+// `Not::not`. Both traits live in `std::ops`.
+//
+// Hint: Like the binary operator traits, `Neg` and `Not` have an associated
+// type `Output` for the result of the operation. It is usually `Self`.
+
+use std::ops::{Neg, Not};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Vector2D {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Flags(u8);
+
+// TODO: Implement `Neg` for `Vector2D` by negating both components.
+impl Neg for Vector2D {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+// TODO: Implement `Not` for `Flags` by inverting all bits.
+impl Not for Flags {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negate_vector() {
+        let v = Vector2D { x: 1.5, y: -2.0 };
+        assert_eq!(-v, Vector2D { x: -1.5, y: 2.0 });
+        assert_eq!(-(-v), v);
+    }
+
+    #[test]
+    fn invert_flags() {
+        assert_eq!(!Flags(0b1010_0000), Flags(0b0101_1111));
+        assert_eq!(!Flags(0), Flags(u8::MAX));
+    }
+}
@@ -0,0 +1,59 @@
+// A default method in a trait can call the other methods of the same trait,
+// even the ones without a default implementation. Implementors then only have
+// to provide the required methods to get the default ones for free.
+//
+// Hint: `doubled` already has a body in the trait. Implementors that don't
+// override it inherit that body, which calls their own `value`.
+
+trait Doubler {
+    fn value(&self) -> i32;
+
+    fn doubled(&self) -> i32 {
+        self.value() * 2
+    }
+}
+
+struct Constant(i32);
+
+struct Sum {
+    a: i32,
+    b: i32,
+}
+
+// TODO: Implement the required method `value`. Don't override `doubled`.
+impl Doubler for Constant {
+    fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+// TODO: Implement the required method `value` returning `a + b`. Don't
+// override `doubled`.
+impl Doubler for Sum {
+    fn value(&self) -> i32 {
+        self.a + self.b
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant() {
+        let c = Constant(21);
+        assert_eq!(c.value(), 21);
+        assert_eq!(c.doubled(), 42);
+    }
+
+    #[test]
+    fn sum() {
+        let s = Sum { a: 3, b: -7 };
+        assert_eq!(s.value(), -4);
+        assert_eq!(s.doubled(), -8);
+    }
+}
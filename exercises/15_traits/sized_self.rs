@@ -0,0 +1,81 @@
+// A trait method that returns `Self` by value, like a constructor, can't be
+// called on a trait object because the concrete type (and its size) isn't
+// known there.
+//
+// Hint: Adding `where Self: Sized` to such a method excludes it from trait
+// objects. The trait can then still be used as `dyn Factory` for its other
+// methods, while `Widget::create()` keeps working on concrete types.
+
+trait Factory {
+    fn create() -> Self
+    where
+        Self: Sized;
+
+    fn name(&self) -> String;
+}
+
+#[derive(Debug, PartialEq)]
+struct Widget {
+    size: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Gadget {
+    label: String,
+    enabled: bool,
+}
+
+// TODO: Implement `Factory` for `Widget`. A new widget has the size 1.
+impl Factory for Widget {
+    fn create() -> Self {
+        Self { size: 1 }
+    }
+
+    fn name(&self) -> String {
+        String::from("widget")
+    }
+}
+
+// TODO: Implement `Factory` for `Gadget`. A new gadget has the label "new"
+// and is enabled.
+impl Factory for Gadget {
+    fn create() -> Self {
+        Self {
+            label: String::from("new"),
+            enabled: true,
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from("gadget")
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_widget() {
+        assert_eq!(Widget::create(), Widget { size: 1 });
+    }
+
+    #[test]
+    fn create_gadget() {
+        let gadget = Gadget::create();
+        assert_eq!(gadget.label, "new");
+        assert!(gadget.enabled);
+    }
+
+    #[test]
+    fn still_object_safe() {
+        let things: Vec<Box<dyn Factory>> =
+            vec![Box::new(Widget::create()), Box::new(Gadget::create())];
+        let names: Vec<String> = things.iter().map(|thing| thing.name()).collect();
+        assert_eq!(names, ["widget", "gadget"]);
+    }
+}
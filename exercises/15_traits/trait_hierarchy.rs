@@ -0,0 +1,59 @@
+// A trait can require that its implementors also implement another trait,
+// called its supertrait. `trait Pet: Animal` means that every `Pet` is an
+// `Animal` too.
+//
+// Hint: A type must implement the supertrait separately before it can
+// implement the subtrait. Afterwards, methods of both traits can be called on
+// a `&dyn Pet` because the supertrait's methods are part of it.
+
+trait Animal {
+    fn name(&self) -> String;
+}
+
+trait Pet: Animal {
+    fn owner(&self) -> String;
+}
+
+struct Dog {
+    name: String,
+    owner: String,
+}
+
+// TODO: Implement `Animal` for `Dog`.
+impl Animal for Dog {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+// TODO: Implement `Pet` for `Dog`.
+impl Pet for Dog {
+    fn owner(&self) -> String {
+        self.owner.clone()
+    }
+}
+
+fn describe(pet: &dyn Pet) -> String {
+    format!("{} belongs to {}", pet.name(), pet.owner())
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn through_trait_object() {
+        let dog = Dog {
+            name: String::from("Rex"),
+            owner: String::from("Alice"),
+        };
+        let pet: &dyn Pet = &dog;
+        assert_eq!(pet.name(), "Rex");
+        assert_eq!(pet.owner(), "Alice");
+        assert_eq!(describe(pet), "Rex belongs to Alice");
+    }
+}
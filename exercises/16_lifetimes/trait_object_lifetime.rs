@@ -0,0 +1,39 @@
+// A boxed closure that borrows data can't outlive that data. Since the box
+// hides the closure's concrete type, its trait object type has to carry this
+// restriction as a lifetime bound.
+//
+// Hint: Without an explicit bound, `Box<dyn Trait>` means
+// `Box<dyn Trait + 'static>`, which a closure borrowing `s` can't satisfy.
+// Writing `+ '_` (or `+ 'a` with a named lifetime) ties the trait object to
+// the lifetime of the borrowed argument.
+
+// TODO: Add the missing lifetime bound to the returned trait object.
+fn make_printer(s: &str) -> Box<dyn Fn() -> String + '_> {
+    Box::new(move || format!("Printing: {s}"))
+}
+
+fn main() {
+    let message = String::from("Hello");
+    let printer = make_printer(&message);
+    println!("{}", printer());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_borrowed_str() {
+        let text = String::from("borrowed");
+        let printer = make_printer(&text);
+        assert_eq!(printer(), "Printing: borrowed");
+        // Calling it multiple times works as long as `text` is alive.
+        assert_eq!(printer(), "Printing: borrowed");
+    }
+
+    #[test]
+    fn static_str() {
+        let printer = make_printer("literal");
+        assert_eq!(printer(), "Printing: literal");
+    }
+}
@@ -0,0 +1,37 @@
+// Sometimes, a function has to return one of multiple iterators depending on
+// a condition. Since every iterator adapter has its own type, the branches
+// don't have the same type and can't be returned directly.
+//
+// Hint: `impl Iterator` stands for *one* concrete type chosen by the function,
+// so it can't be `Range<i32>` in one branch and `Rev<Range<i32>>` in the other.
+// Boxing both as `Box<dyn Iterator<Item = i32>>` gives them the same type at the
+// cost of a heap allocation and dynamic dispatch.
+
+// TODO: Return the numbers 0 to 4 in ascending order if `ascending` is `true`,
+// in descending order otherwise.
+fn choose(ascending: bool) -> Box<dyn Iterator<Item = i32>> {
+    if ascending {
+        Box::new(0..5)
+    } else {
+        Box::new((0..5).rev())
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascending() {
+        assert_eq!(choose(true).collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn descending() {
+        assert_eq!(choose(false).collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+    }
+}
@@ -0,0 +1,40 @@
+// A closure can take references as parameters, just like a function. If the
+// references point into data owned by the caller, their lifetime has to be
+// spelled out in the closure's trait bound.
+//
+// Hint: Iterating over `&'a [String]` yields `&'a String` items. Calling
+// `as_str()` on them gives `&'a str`, which matches the parameter type of
+// `f: impl Fn(&'a str) -> usize`, so `f` can be called inside the closure
+// passed to `map`.
+
+// TODO: Apply `f` to every item and collect the results.
+fn transform_each<'a>(items: &'a [String], f: impl Fn(&'a str) -> usize) -> Vec<usize> {
+    items.iter().map(|item| f(item.as_str())).collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths() {
+        let items = vec![String::from("a"), String::from("abc"), String::new()];
+        assert_eq!(transform_each(&items, |s| s.len()), [1, 3, 0]);
+    }
+
+    #[test]
+    fn vowel_counts() {
+        let items = vec![String::from("closure"), String::from("rhythm")];
+        let vowels = |s: &str| s.chars().filter(|c| "aeiou".contains(*c)).count();
+        assert_eq!(transform_each(&items, vowels), [3, 0]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(transform_each(&[], str::len).is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+// Closures can use variables from the scope in which they are defined. This
+// makes them handy as arguments to methods like `sort_by_key` which need a
+// little bit of extra context to do their job.
+//
+// Hint: The key closure can use `origin` from the surrounding function
+// directly. Since it only reads `origin`, the closure captures it by reference.
+
+// TODO: Sort `points` by their squared distance to `origin` (closest first).
+fn sort_by_distance(points: &mut [(i32, i32)], origin: (i32, i32)) {
+    points.sort_by_key(|&(x, y)| {
+        let dx = x - origin.0;
+        let dy = y - origin.1;
+        dx * dx + dy * dy
+    });
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_around_origin() {
+        let mut points = vec![(3, 3), (0, 1), (-2, 0), (5, -1)];
+        sort_by_distance(&mut points, (0, 0));
+        assert_eq!(points, [(0, 1), (-2, 0), (3, 3), (5, -1)]);
+    }
+
+    #[test]
+    fn sort_around_other_point() {
+        let mut points = vec![(0, 0), (10, 10), (4, 5)];
+        sort_by_distance(&mut points, (5, 5));
+        assert_eq!(points, [(4, 5), (0, 0), (10, 10)]);
+    }
+
+    #[test]
+    fn ties_keep_order() {
+        // `sort_by_key` is stable: points with the same distance keep their
+        // original order.
+        let mut points = vec![(0, 2), (1, 0), (-1, 0), (0, 0)];
+        sort_by_distance(&mut points, (0, 0));
+        assert_eq!(points, [(0, 0), (1, 0), (-1, 0), (0, 2)]);
+    }
+}
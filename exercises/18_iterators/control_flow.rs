@@ -0,0 +1,50 @@
+// `fold` always visits every element. Sometimes, you want to stop early
+// instead, for example once a certain element is reached. `try_fold` can do
+// this when its closure returns a type that signals whether to go on.
+//
+// Hint: `std::ops::ControlFlow` has the two variants `Continue(acc)` to keep
+// folding with the new accumulator and `Break(value)` to stop immediately.
+// `try_fold` returns the `ControlFlow` of the last call, so both variants need
+// to be unpacked at the end.
+
+use std::ops::ControlFlow;
+
+// TODO: Sum the elements of `v` up to (but excluding) the first negative one.
+fn sum_until_negative(v: &[i32]) -> i32 {
+    let result = v.iter().try_fold(0, |sum, &n| {
+        if n < 0 {
+            ControlFlow::Break(sum)
+        } else {
+            ControlFlow::Continue(sum + n)
+        }
+    });
+
+    match result {
+        ControlFlow::Continue(sum) | ControlFlow::Break(sum) => sum,
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_at_negative() {
+        assert_eq!(sum_until_negative(&[1, 2, 3, -1, 100]), 6);
+    }
+
+    #[test]
+    fn all_non_negative() {
+        assert_eq!(sum_until_negative(&[1, 0, 2, 3]), 6);
+        assert_eq!(sum_until_negative(&[]), 0);
+    }
+
+    #[test]
+    fn immediate_negative() {
+        assert_eq!(sum_until_negative(&[-5, 1, 2]), 0);
+    }
+}
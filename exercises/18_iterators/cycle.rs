@@ -0,0 +1,43 @@
+// `cycle` turns an iterator into an endless one that starts from the beginning
+// again whenever the end is reached. Combined with `take`, you get exactly as
+// many elements as you need.
+//
+// Hint: Calling `cycle()` on an empty iterator doesn't loop forever. It just
+// yields nothing, so `take(len)` stops right away and the result is empty.
+
+// TODO: Return a vector of length `len` that repeats `pattern` over and over.
+fn repeat_pattern(pattern: &[char], len: usize) -> Vec<char> {
+    pattern.iter().copied().cycle().take(len).collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_a_multiple() {
+        assert_eq!(
+            repeat_pattern(&['a', 'b', 'c'], 7),
+            ['a', 'b', 'c', 'a', 'b', 'c', 'a'],
+        );
+    }
+
+    #[test]
+    fn exact_multiple() {
+        assert_eq!(repeat_pattern(&['x', 'y'], 4), ['x', 'y', 'x', 'y']);
+    }
+
+    #[test]
+    fn zero_length() {
+        assert!(repeat_pattern(&['a', 'b'], 0).is_empty());
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert!(repeat_pattern(&[], 5).is_empty());
+    }
+}
@@ -0,0 +1,42 @@
+// Some iterators can be walked from both ends. They implement the trait
+// `DoubleEndedIterator` which provides the `rev` adapter.
+//
+// Hint: Slice iterators are double-ended, so `v.iter().rev()` yields the
+// elements from the back. `take(3)` then keeps the last three of them, but in
+// reverse order. `take` of a double-ended iterator with a known length is
+// double-ended too, so a second `rev` restores the original order.
+
+// TODO: Return the last three elements of `v` in their original order. If `v`
+// has fewer than three elements, return all of them.
+fn last_three(v: &[i32]) -> Vec<i32> {
+    v.iter().rev().take(3).rev().copied().collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long() {
+        assert_eq!(last_three(&[1, 2, 3, 4, 5, 6, 7]), [5, 6, 7]);
+    }
+
+    #[test]
+    fn exactly_three() {
+        assert_eq!(last_three(&[1, 2, 3]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn fewer_than_three() {
+        assert_eq!(last_three(&[8, 9]), [8, 9]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(last_three(&[]).is_empty());
+    }
+}
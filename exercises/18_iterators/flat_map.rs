@@ -0,0 +1,42 @@
+// If every element of an iterator turns into multiple elements, you end up
+// with an iterator of iterators. `flat_map` maps and flattens in one step.
+//
+// Hint: `flat_map(f)` does the same as `map(f).flatten()`. Use `flat_map`
+// when you start with a mapping closure and `flatten` when the elements are
+// already iterable (e.g. an iterator over vectors).
+
+// TODO: Split all sentences into words and return them in order.
+fn flatten_words(sentences: &[&str]) -> Vec<String> {
+    sentences
+        .iter()
+        .flat_map(|s| s.split_whitespace())
+        .map(String::from)
+        .collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiple_sentences() {
+        assert_eq!(
+            flatten_words(&["hello world", "  rust  is fun", "ok"]),
+            ["hello", "world", "rust", "is", "fun", "ok"],
+        );
+    }
+
+    #[test]
+    fn empty_sentence() {
+        assert_eq!(flatten_words(&["one", "", "two"]), ["one", "two"]);
+    }
+
+    #[test]
+    fn no_sentences() {
+        assert!(flatten_words(&[]).is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+// A closure that moves a captured value out of itself can only be called once,
+// because the value is gone after the first call. Such closures only implement
+// the `FnOnce` trait, not `FnMut` or `Fn`.
+//
+// Hint: `FnOnce` is the least restrictive bound for the caller: every closure
+// implements it. A function taking `F: FnOnce() -> String` can call `f()`
+// exactly one time, which is all that a consuming closure allows.
+
+// TODO: Add the trait bound and call `f`.
+fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consuming_closure() {
+        let name = String::from("Ferris");
+        // `move` moves `name` into the closure and calling the closure moves
+        // it out again. Calling `give_back` a second time would be a compiler
+        // error.
+        let give_back = move || name;
+        assert_eq!(call_once(give_back), "Ferris");
+    }
+
+    #[test]
+    fn non_consuming_closure() {
+        // Closures that could be called multiple times are accepted too.
+        let greeting = String::from("Hello");
+        assert_eq!(call_once(|| format!("{greeting}!")), "Hello!");
+        assert_eq!(greeting, "Hello");
+    }
+}
@@ -0,0 +1,41 @@
+// Besides the closure traits `Fn`, `FnMut` and `FnOnce`, Rust has function
+// pointer types like `fn(i32) -> i32`. A function pointer can point to a named
+// function, but also to a closure.
+//
+// Hint: Only closures that don't capture anything from their environment can
+// be coerced to a `fn` pointer. A capturing closure needs data that a plain
+// function pointer has no place to store, so it requires a generic `F: Fn(..)`
+// parameter instead.
+
+// TODO: Complete the type of `f` so that it is a function pointer taking and
+// returning an `i32`.
+fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+    f(x)
+}
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_function() {
+        assert_eq!(apply(double, 21), 42);
+    }
+
+    #[test]
+    fn non_capturing_closure() {
+        assert_eq!(apply(|x| x - 1, 10), 9);
+
+        // The closure is coerced to a function pointer.
+        let square: fn(i32) -> i32 = |x| x * x;
+        assert_eq!(apply(square, 7), 49);
+    }
+}
@@ -0,0 +1,43 @@
+// When a long iterator chain doesn't do what you expect, it can be useful to
+// look at the elements while they flow through it. The `inspect` adapter is
+// made for this.
+//
+// Hint: `inspect` calls a closure with a reference to every element and then
+// passes the element on unchanged. It is lazy like every other adapter, so the
+// closure only runs for elements that are actually consumed.
+
+// TODO: Sum the elements of `v` and use `inspect` to push each visited element
+// into `log`.
+fn sum_logged(v: &[i32], log: &mut Vec<i32>) -> i32 {
+    v.iter().inspect(|&&n| log.push(n)).sum()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_and_log() {
+        let mut log = Vec::new();
+        assert_eq!(sum_logged(&[4, -1, 7, 2], &mut log), 12);
+        assert_eq!(log, [4, -1, 7, 2]);
+    }
+
+    #[test]
+    fn log_is_appended() {
+        let mut log = vec![0];
+        assert_eq!(sum_logged(&[1, 2], &mut log), 3);
+        assert_eq!(log, [0, 1, 2]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut log = Vec::new();
+        assert_eq!(sum_logged(&[], &mut log), 0);
+        assert!(log.is_empty());
+    }
+}
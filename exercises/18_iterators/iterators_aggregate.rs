@@ -0,0 +1,39 @@
+// Iterators come with methods that reduce all elements to a single value, like
+// `sum`, `product`, `min` and `max`.
+//
+// Hint: `min()` and `max()` return an `Option` because an empty iterator has no
+// smallest or largest element. `sum()` of an empty iterator on the other hand
+// is just 0. The `?` operator works on `Option` too.
+
+// TODO: Return the minimum, maximum and sum of `v`, or `None` if `v` is empty.
+fn stats(v: &[i32]) -> Option<(i32, i32, i32)> {
+    let min = *v.iter().min()?;
+    let max = *v.iter().max()?;
+    let sum = v.iter().sum();
+
+    Some((min, max, sum))
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(stats(&[]), None);
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(stats(&[7]), Some((7, 7, 7)));
+    }
+
+    #[test]
+    fn multiple_elements() {
+        assert_eq!(stats(&[3, -4, 10, 0, 1]), Some((-4, 10, 10)));
+    }
+}
@@ -0,0 +1,42 @@
+// Iterator adapters like `filter` and `map` are lazy: they don't do any work
+// until something consumes the iterator. A function can keep this laziness by
+// returning the iterator itself instead of collecting it into a `Vec`.
+//
+// Hint: Returning `impl Iterator<Item = i32>` lets you hand back the adapter
+// chain without naming its (very long) concrete type. Nothing is allocated and
+// the caller decides how many elements are actually computed.
+
+// TODO: Complete the signature and the body. The returned iterator should only
+// keep the positive numbers of `input` and double them.
+fn pipeline<I: IntoIterator<Item = i32>>(input: I) -> impl Iterator<Item = i32> {
+    input.into_iter().filter(|&n| n > 0).map(|n| n * 2)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_and_doubles() {
+        let result: Vec<i32> = pipeline(vec![-2, 1, 0, 3, -5, 4]).collect();
+        assert_eq!(result, [2, 6, 8]);
+    }
+
+    #[test]
+    fn is_lazy() {
+        // The input is infinite. This only terminates if the pipeline is lazy.
+        let mut iter = pipeline((-3..).step_by(2)).take(2);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(pipeline(Vec::new()).next(), None);
+    }
+}
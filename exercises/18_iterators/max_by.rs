@@ -0,0 +1,41 @@
+// `min()` and `max()` only work for iterators over types implementing `Ord`.
+// Floating point numbers don't, but you can still find the smallest or largest
+// one by telling the iterator how to compare them.
+//
+// Hint: `f64` is only `PartialOrd` because `NaN` can't be compared with
+// anything. `min_by(|a, b| ...)` takes a closure returning an `Ordering`, which
+// you get from `a.partial_cmp(b)` (an `Option<Ordering>`) if neither value is
+// `NaN`.
+
+// TODO: Return the value of `v` with the smallest absolute value, or `None` if
+// `v` is empty. You can assume that `v` doesn't contain `NaN`.
+fn closest_to_zero(v: &[f64]) -> Option<f64> {
+    v.iter()
+        .copied()
+        .min_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap())
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed() {
+        assert_eq!(closest_to_zero(&[3.5, -0.25, 1.0, 0.5, -7.0]), Some(-0.25));
+        assert_eq!(closest_to_zero(&[-2.0, 1.5, 4.0]), Some(1.5));
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(closest_to_zero(&[-42.0]), Some(-42.0));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(closest_to_zero(&[]), None);
+    }
+}
@@ -0,0 +1,54 @@
+// Your own iterator can borrow the data it iterates over. Here, `Pairs` walks
+// over a slice and yields every two neighbouring elements as a pair.
+//
+// Hint: The struct holds the borrowed slice and the index of the next pair.
+// `next()` returns `None` once there is no element after the current index,
+// and otherwise yields the pair and moves the index forward by one.
+
+struct Pairs<'a> {
+    slice: &'a [i32],
+    index: usize,
+}
+
+impl<'a> Pairs<'a> {
+    fn new(slice: &'a [i32]) -> Self {
+        Self { slice, index: 0 }
+    }
+}
+
+impl Iterator for Pairs<'_> {
+    type Item = (i32, i32);
+
+    // TODO: Yield the pair of the element at `index` and the one after it.
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = *self.slice.get(self.index)?;
+        let second = *self.slice.get(self.index + 1)?;
+        self.index += 1;
+        Some((first, second))
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_pairs() {
+        let pairs: Vec<(i32, i32)> = Pairs::new(&[1, 2, 3, 4]).collect();
+        assert_eq!(pairs, [(1, 2), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn one_element() {
+        assert_eq!(Pairs::new(&[1]).next(), None);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Pairs::new(&[]).next(), None);
+    }
+}
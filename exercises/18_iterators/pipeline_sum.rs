@@ -0,0 +1,38 @@
+// Iterator adapters can be chained into a pipeline that processes every
+// element from start to end before moving on to the next one.
+//
+// Hint: Try to solve this with a single chain of `filter`, `map` and `sum`
+// without collecting into intermediate vectors. The compiler fuses the chain
+// into one loop that doesn't allocate anything.
+
+// TODO: Return the sum of the squares of all even numbers in `v`.
+fn sum_of_squares_of_evens(v: &[i32]) -> i32 {
+    v.iter().filter(|&&n| n % 2 == 0).map(|&n| n * n).sum()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed() {
+        // 4 + 16 + 36
+        assert_eq!(sum_of_squares_of_evens(&[1, 2, 3, 4, 5, 6]), 56);
+        // Negative even numbers count too: 4 + 0
+        assert_eq!(sum_of_squares_of_evens(&[-2, -1, 0]), 4);
+    }
+
+    #[test]
+    fn all_odd() {
+        assert_eq!(sum_of_squares_of_evens(&[1, 3, 5, -7]), 0);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sum_of_squares_of_evens(&[]), 0);
+    }
+}
@@ -0,0 +1,68 @@
+// Iterator adapters are structs that wrap another iterator and implement
+// `Iterator` themselves. Let's write our own adapter that turns a sequence of
+// numbers into the sequence of their running averages.
+//
+// Hint: To compute the mean of all elements seen so far, the struct needs to
+// remember how many elements it has seen and what their sum is. Update both in
+// `next()` every time the inner iterator yields a value.
+
+struct RunningAverage<I> {
+    inner: I,
+    count: u32,
+    sum: f64,
+}
+
+impl<I: Iterator<Item = f64>> RunningAverage<I> {
+    fn new(inner: I) -> Self {
+        Self {
+            inner,
+            count: 0,
+            sum: 0.0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = f64>> Iterator for RunningAverage<I> {
+    type Item = f64;
+
+    // TODO: Return the mean of all values yielded by `inner` so far.
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.inner.next()?;
+        self.count += 1;
+        self.sum += value;
+        Some(self.sum / f64::from(self.count))
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{a} isn't close to {e}");
+        }
+    }
+
+    #[test]
+    fn running_means() {
+        let means: Vec<f64> = RunningAverage::new([2.0, 4.0, 6.0].into_iter()).collect();
+        assert_close(&means, &[2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn non_integer_means() {
+        let means: Vec<f64> = RunningAverage::new([1.0, 2.0, 0.5].into_iter()).collect();
+        assert_close(&means, &[1.0, 1.5, 3.5 / 3.0]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(RunningAverage::new(std::iter::empty()).next(), None);
+    }
+}
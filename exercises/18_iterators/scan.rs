@@ -0,0 +1,46 @@
+// `scan` is like `map`, but it carries a piece of state from one element to
+// the next. This is useful for running totals, running maximums and similar
+// sequences that depend on all previous elements.
+//
+// Hint: `scan(initial, |state, x| ...)` passes a mutable reference to the
+// state to the closure for every element. Update `*state` and return
+// `Some(value)` to yield `value` (returning `None` would end the iteration).
+
+// TODO: Return the maximum of all elements seen so far for every element.
+fn running_max(v: &[i32]) -> Vec<i32> {
+    v.iter()
+        .scan(i32::MIN, |max, &n| {
+            *max = (*max).max(n);
+            Some(*max)
+        })
+        .collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increasing() {
+        assert_eq!(running_max(&[1, 2, 3, 4]), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn decreasing() {
+        assert_eq!(running_max(&[4, 3, 2, 1]), [4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn mixed() {
+        assert_eq!(running_max(&[-5, 2, -1, 7, 3, 8]), [-5, 2, 2, 7, 7, 8]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(running_max(&[]).is_empty());
+    }
+}
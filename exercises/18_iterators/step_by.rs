@@ -0,0 +1,34 @@
+// Ranges are iterators too. Instead of visiting every number in a range, you
+// can skip some of them with `step_by`.
+//
+// Hint: `step_by(n)` yields the first element and then every `n`-th element
+// after it. The step must not be 0, otherwise it panics.
+
+// TODO: Return all even numbers from 0 up to and including `n`.
+fn evens_up_to(n: u32) -> Vec<u32> {
+    (0..=n).step_by(2).collect()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn up_to_even() {
+        assert_eq!(evens_up_to(10), [0, 2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn up_to_odd() {
+        assert_eq!(evens_up_to(7), [0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn up_to_zero() {
+        assert_eq!(evens_up_to(0), [0]);
+    }
+}
@@ -0,0 +1,45 @@
+// `for_each` runs a closure for every element of an iterator. Its fallible
+// sibling `try_for_each` stops as soon as the closure returns an error.
+//
+// Hint: The closure passed to `try_for_each` returns a `Result<(), E>`.
+// `try_for_each` returns the first `Err` it encounters without visiting the
+// remaining elements, or `Ok(())` if every call succeeded.
+
+// TODO: Return an error for the first negative number in `v`, for example
+// "-3 at index 2 is negative". Return `Ok(())` if there is none.
+fn validate_all(v: &[i32]) -> Result<(), String> {
+    v.iter().enumerate().try_for_each(|(index, &n)| {
+        if n < 0 {
+            Err(format!("{n} at index {index} is negative"))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_non_negative() {
+        assert_eq!(validate_all(&[0, 1, 2, 3]), Ok(()));
+    }
+
+    #[test]
+    fn first_negative() {
+        assert_eq!(
+            validate_all(&[1, 2, -3, -4]),
+            Err(String::from("-3 at index 2 is negative")),
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(validate_all(&[]), Ok(()));
+    }
+}
@@ -0,0 +1,52 @@
+// A function that sometimes has to modify its input and sometimes doesn't can
+// return a `Cow`. It only allocates a modified copy when needed and otherwise
+// hands back the borrowed input.
+//
+// Hint: `Cow<[i32]>` is either `Cow::Borrowed(&[i32])` or
+// `Cow::Owned(Vec<i32>)`. If the slice is already sorted, return it borrowed
+// to avoid the clone. `v.is_sorted()` checks that for you.
+
+use std::borrow::Cow;
+
+// TODO: Return `v` sorted. Only clone it if it isn't sorted yet.
+fn ensure_sorted(v: &[i32]) -> Cow<'_, [i32]> {
+    if v.is_sorted() {
+        Cow::Borrowed(v)
+    } else {
+        let mut sorted = v.to_vec();
+        sorted.sort();
+        Cow::Owned(sorted)
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn already_sorted() {
+        let v = [1, 2, 2, 5];
+        let result = ensure_sorted(&v);
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(*result, [1, 2, 2, 5]);
+    }
+
+    #[test]
+    fn unsorted() {
+        let v = [3, 1, 2];
+        let result = ensure_sorted(&v);
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(*result, [1, 2, 3]);
+        // The input wasn't changed.
+        assert_eq!(v, [3, 1, 2]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(matches!(ensure_sorted(&[]), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,58 @@
+// This exercise builds a tiny smart pointer. Implementing `Deref` lets you read
+// the wrapped value through `*`, and implementing `DerefMut` additionally lets
+// you change it.
+//
+// Hint: `DerefMut` builds on `Deref` and reuses its `Target` type, so it only
+// needs the `deref_mut` method returning `&mut Self::Target`. With it, `*b = x`
+// works and methods taking `&mut self` on the inner type can be called
+// directly on the box thanks to deref coercion.
+
+use std::ops::{Deref, DerefMut};
+
+struct MyBox<T>(T);
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// TODO: Implement `DerefMut` for `MyBox`.
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_and_write() {
+        let mut b = MyBox(5);
+        assert_eq!(*b, 5);
+
+        *b = 10;
+        assert_eq!(*b, 10);
+
+        *b += 1;
+        assert_eq!(*b, 11);
+    }
+
+    #[test]
+    fn deref_coercion() {
+        let mut b = MyBox(String::from("Hello"));
+        // `&String` methods through `Deref`.
+        assert_eq!(b.len(), 5);
+        // `&mut String` methods through `DerefMut`.
+        b.push_str(", world");
+        assert_eq!(*b, "Hello, world");
+    }
+}
@@ -0,0 +1,50 @@
+// The closure passed to `thread::spawn` can return a value. The spawning
+// thread receives this value when it joins the thread through its
+// `JoinHandle`.
+//
+// Hint: `handle.join()` waits for the thread to finish and returns a `Result`.
+// It is `Ok` with the closure's return value, or `Err` if the thread panicked.
+// Joining the handles in the order in which they were created preserves the
+// order of the inputs, no matter which thread finishes first.
+
+use std::thread::{self, JoinHandle};
+
+fn parallel_squares(inputs: Vec<u64>) -> Vec<u64> {
+    // TODO: Spawn one thread per input that computes its square.
+    let handles: Vec<JoinHandle<u64>> = inputs
+        .into_iter()
+        .map(|n| thread::spawn(move || n * n))
+        .collect();
+
+    // TODO: Join all threads and collect their results in order.
+    handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect()
+}
+
+fn main() {
+    println!("{:?}", parallel_squares(vec![1, 2, 3]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_is_preserved() {
+        assert_eq!(parallel_squares(vec![3, 1, 4, 1, 5]), [9, 1, 16, 1, 25]);
+    }
+
+    #[test]
+    fn many_inputs() {
+        let inputs: Vec<u64> = (0..200).collect();
+        let expected: Vec<u64> = inputs.iter().map(|n| n * n).collect();
+        assert_eq!(parallel_squares(inputs), expected);
+    }
+
+    #[test]
+    fn no_inputs() {
+        assert!(parallel_squares(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+// To look up a key in a `HashMap<String, _>`, you don't need to allocate a new
+// `String`. A plain string slice works too, thanks to the `Borrow` trait.
+//
+// Hint: `HashMap::get` accepts any `&Q` where the key type implements
+// `Borrow<Q>`. `String` implements `Borrow<str>`, and a `String` and its `str`
+// have the same hash, so `map.get(key)` works directly with `key: &str`.
+
+use std::collections::HashMap;
+
+// TODO: Return the score stored for `key`, if there is one.
+fn get_score(map: &HashMap<String, i32>, key: &str) -> Option<i32> {
+    map.get(key).copied()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scores() -> HashMap<String, i32> {
+        HashMap::from([(String::from("alice"), 10), (String::from("bob"), 7)])
+    }
+
+    #[test]
+    fn existing_key() {
+        let map = scores();
+        assert_eq!(get_score(&map, "alice"), Some(10));
+
+        let owned = String::from("bob");
+        assert_eq!(get_score(&map, &owned), Some(7));
+    }
+
+    #[test]
+    fn missing_key() {
+        assert_eq!(get_score(&scores(), "carol"), None);
+    }
+}
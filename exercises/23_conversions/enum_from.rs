@@ -0,0 +1,65 @@
+// An enum that wraps different concrete types (a sum type) is a very common
+// pattern, for example for error hierarchies. Implementing `From` for each
+// wrapped type makes building the enum as easy as calling `.into()`.
+//
+// Hint: `impl From<Circle> for Shape` only needs to say which variant the
+// circle goes into. The matching `Into<Shape>` for `Circle` comes for free.
+
+#[derive(Debug, PartialEq)]
+struct Circle {
+    radius: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Square {
+    side: f64,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle(Circle),
+    Square(Square),
+}
+
+// TODO: Implement `From<Circle>` for `Shape`.
+impl From<Circle> for Shape {
+    fn from(circle: Circle) -> Self {
+        Self::Circle(circle)
+    }
+}
+
+// TODO: Implement `From<Square>` for `Shape`.
+impl From<Square> for Shape {
+    fn from(square: Square) -> Self {
+        Self::Square(square)
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_into_shape() {
+        let shape: Shape = Circle { radius: 1.0 }.into();
+        assert!(matches!(shape, Shape::Circle(Circle { radius }) if radius == 1.0));
+    }
+
+    #[test]
+    fn square_into_shape() {
+        let shape: Shape = Square { side: 2.0 }.into();
+        assert!(matches!(shape, Shape::Square(Square { side }) if side == 2.0));
+    }
+
+    #[test]
+    fn from_directly() {
+        assert_eq!(
+            Shape::from(Square { side: 3.0 }),
+            Shape::Square(Square { side: 3.0 }),
+        );
+    }
+}
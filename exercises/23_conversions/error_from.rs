@@ -0,0 +1,69 @@
+// A function that calls different fallible operations has to return one error
+// type that covers all of them. An enum with one variant per error source works
+// well for this. Implementing `From` for each wrapped error makes the `?`
+// operator do the wrapping for us.
+//
+// Hint: When `?` encounters an `Err(e)`, it returns `Err(From::from(e))`. So
+// after implementing `From<io::Error>` and `From<ParseIntError>` for
+// `AppError`, both kinds of errors can be propagated with `?`.
+
+use std::io::{self, Read};
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+enum AppError {
+    Io(io::Error),
+    Parse(ParseIntError),
+}
+
+// TODO: Implement `From<io::Error>` for `AppError`.
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+// TODO: Implement `From<ParseIntError>` for `AppError`.
+impl From<ParseIntError> for AppError {
+    fn from(e: ParseIntError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+// Reads the whole input and parses it as a number.
+// Don't change this function.
+fn read_number<R: Read>(mut reader: R) -> Result<i32, AppError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let number = input.trim().parse()?;
+    Ok(number)
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_number() {
+        assert_eq!(read_number("42\n".as_bytes()).unwrap(), 42);
+    }
+
+    #[test]
+    fn io_error() {
+        // Reading invalid UTF-8 into a `String` fails with an I/O error.
+        let bytes: &[u8] = &[0xff, 0xfe];
+        assert!(matches!(read_number(bytes), Err(AppError::Io(_))));
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!(matches!(
+            read_number("forty-two".as_bytes()),
+            Err(AppError::Parse(_)),
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+// `TryFrom` isn't limited to owned values. Implementing `TryFrom<&str>` lets
+// you parse a borrowed string slice into your own type, for example a color
+// written in the hexadecimal notation `#RRGGBB` known from CSS.
+//
+// Hint: `u8::from_str_radix(s, 16)` parses a string of hexadecimal digits into
+// a `u8`. Each color component consists of two digits.
+
+#[derive(Debug, PartialEq)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum ParseRgbError {
+    // The string doesn't start with `#`.
+    MissingHash,
+    // There aren't exactly 6 characters after `#`.
+    BadLen,
+    // A character isn't a hexadecimal digit.
+    InvalidHex,
+}
+
+impl TryFrom<&str> for Rgb {
+    type Error = ParseRgbError;
+
+    // TODO: Parse a string like "#FF8800" into an `Rgb` color.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let hex = s.strip_prefix('#').ok_or(ParseRgbError::MissingHash)?;
+
+        if hex.len() != 6 {
+            return Err(ParseRgbError::BadLen);
+        }
+
+        // This also ensures that all characters are ASCII so that the slicing
+        // below can't split a multi-byte character.
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseRgbError::InvalidHex);
+        }
+
+        let component =
+            |range| u8::from_str_radix(&hex[range], 16).map_err(|_| ParseRgbError::InvalidHex);
+
+        Ok(Self {
+            r: component(0..2)?,
+            g: component(2..4)?,
+            b: component(4..6)?,
+        })
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            Rgb::try_from("#FF8800"),
+            Ok(Rgb {
+                r: 255,
+                g: 136,
+                b: 0,
+            }),
+        );
+        assert_eq!(
+            Rgb::try_from("#0a1B2c"),
+            Ok(Rgb {
+                r: 10,
+                g: 27,
+                b: 44,
+            }),
+        );
+    }
+
+    #[test]
+    fn missing_hash() {
+        assert_eq!(Rgb::try_from("FF8800"), Err(ParseRgbError::MissingHash));
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert_eq!(Rgb::try_from("#FF880"), Err(ParseRgbError::BadLen));
+        assert_eq!(Rgb::try_from("#FF88000"), Err(ParseRgbError::BadLen));
+        assert_eq!(Rgb::try_from("#"), Err(ParseRgbError::BadLen));
+    }
+
+    #[test]
+    fn non_hex_digits() {
+        assert_eq!(Rgb::try_from("#GG8800"), Err(ParseRgbError::InvalidHex));
+        assert_eq!(Rgb::try_from("#+F8800"), Err(ParseRgbError::InvalidHex));
+        assert_eq!(Rgb::try_from("#ü8800"), Err(ParseRgbError::InvalidHex));
+    }
+}
@@ -0,0 +1,37 @@
+// `From` can be implemented generically. A single `impl` block then covers the
+// conversion from every possible type into a generic wrapper.
+//
+// Hint: `impl<T> From<T> for Wrapper<T>` provides `Wrapper::from(value)` for
+// any `T`. Because the standard library implements `Into` for every `From`,
+// `value.into()` works as well once the target type is known.
+
+#[derive(Debug, PartialEq)]
+struct Wrapper<T>(T);
+
+// TODO: Implement `From<T>` for `Wrapper<T>` for every type `T`.
+impl<T> From<T> for Wrapper<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_integer() {
+        let wrapped: Wrapper<i32> = 42.into();
+        assert_eq!(wrapped, Wrapper(42));
+    }
+
+    #[test]
+    fn wrap_string() {
+        let wrapped: Wrapper<String> = String::from("hello").into();
+        assert_eq!(wrapped.0, "hello");
+    }
+}
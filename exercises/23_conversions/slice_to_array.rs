@@ -0,0 +1,38 @@
+// The length of a slice is only known at runtime, while the length of an array
+// is part of its type. Converting a slice into an array therefore can fail and
+// is done with `TryFrom`/`TryInto`.
+//
+// Hint: `<[T; N]>::try_from(slice)` (or `slice.try_into()`) succeeds only if
+// the slice has exactly `N` elements. `Result::ok` turns the result into an
+// `Option`.
+
+// TODO: Convert `slice` into an array of 3 elements, or return `None` if its
+// length isn't 3.
+fn to_triple(slice: &[i32]) -> Option<[i32; 3]> {
+    slice.try_into().ok()
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_length() {
+        assert_eq!(to_triple(&[1, 2, 3]), Some([1, 2, 3]));
+    }
+
+    #[test]
+    fn too_short() {
+        assert_eq!(to_triple(&[1, 2]), None);
+        assert_eq!(to_triple(&[]), None);
+    }
+
+    #[test]
+    fn too_long() {
+        assert_eq!(to_triple(&[1, 2, 3, 4]), None);
+    }
+}
@@ -0,0 +1,74 @@
+// A newtype can guarantee that its content is always valid if the only way to
+// create it is a conversion that checks the input. Every function receiving a
+// `Username` can then rely on it being valid without checking again.
+//
+// Hint: Implement `TryFrom<String>` for the newtype. Return an error for
+// invalid input and only wrap the string if all checks pass. In a real
+// program, the newtype would live in its own module with a private field so
+// that the conversion can't be bypassed.
+
+const MAX_LEN: usize = 20;
+
+#[derive(Debug, PartialEq)]
+struct Username(String);
+
+#[derive(Debug, PartialEq)]
+enum UsernameError {
+    Empty,
+    TooLong,
+}
+
+impl TryFrom<String> for Username {
+    type Error = UsernameError;
+
+    // TODO: Reject empty names and names with more than `MAX_LEN` characters.
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        if name.is_empty() {
+            return Err(UsernameError::Empty);
+        }
+
+        if name.chars().count() > MAX_LEN {
+            return Err(UsernameError::TooLong);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+fn main() {
+    // You can optionally experiment here.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            Username::try_from(String::from("ferris")),
+            Ok(Username(String::from("ferris"))),
+        );
+        let longest = "a".repeat(MAX_LEN);
+        assert!(Username::try_from(longest).is_ok());
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Username::try_from(String::new()), Err(UsernameError::Empty));
+    }
+
+    #[test]
+    fn too_long() {
+        assert_eq!(
+            Username::try_from("x".repeat(50)),
+            Err(UsernameError::TooLong),
+        );
+    }
+
+    #[test]
+    fn with_try_into() {
+        let result: Result<Username, _> = String::from("crab").try_into();
+        assert!(result.is_ok());
+    }
+}